@@ -0,0 +1,353 @@
+//! Contains the `ZeroArena` bump allocator,for allocating many zeroed values at once.
+
+use crate::Zeroable;
+
+use alloc::{
+    alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout},
+    vec::Vec,
+};
+
+use core::{
+    cell::{Cell, RefCell},
+    cmp, fmt, mem,
+    ptr::{self, NonNull},
+    slice,
+};
+
+/// The default size in bytes of the pages that a `ZeroArena` allocates.
+pub const DEFAULT_PAGE_SIZE: usize = 64 * 1024;
+
+/// The minimum alignment of the pages that a `ZeroArena` allocates.
+///
+/// Values with a larger alignment get a page allocated with their alignment.
+pub const PAGE_ALIGN: usize = 16;
+
+/// A bump allocator that hands out zeroed values of `Zeroable` types.
+///
+/// Memory is obtained in pages with `alloc_zeroed`,
+/// and handed out by bumping an offset into the current page.
+/// Individual values can't be deallocated,
+/// all of them are freed at once with [`reset`](#method.reset),
+/// which zeroes the used part of every page (with `ptr::write_bytes`) to reuse it,
+/// or when the arena is dropped.
+///
+/// # Destructors
+///
+/// The destructors of the values allocated with
+/// [`alloc_zeroed`](#method.alloc_zeroed) and
+/// [`alloc_slice_zeroed`](#method.alloc_slice_zeroed)
+/// are never run.
+///
+/// The destructors of the values allocated with
+/// [`alloc_zeroed_with_drop`](#method.alloc_zeroed_with_drop)
+/// are run when the arena is reset or dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{arena::ZeroArena, Zeroable};
+///
+/// #[derive(Debug, PartialEq, Zeroable)]
+/// struct Particle {
+///     position: [f32; 3],
+///     velocity: [f32; 3],
+///     age: u32,
+/// }
+///
+/// let mut arena = ZeroArena::new();
+///
+/// for tick in 0..3 {
+///     let particle = arena.alloc_zeroed::<Particle>();
+///     assert_eq!(particle.age, 0);
+///     particle.age = tick;
+///
+///     let counters = arena.alloc_slice_zeroed::<u64>(1000);
+///     assert!(counters.iter().all(|&x| x == 0));
+///     counters[999] = 1;
+///
+///     // Frees every allocation at once,zeroing the memory to reuse it in the next tick.
+///     arena.reset();
+/// }
+///
+/// ```
+pub struct ZeroArena {
+    pages: RefCell<Vec<Page>>,
+    /// The index of the page that values are allocated in.
+    current: Cell<usize>,
+    /// The offset into the current page where the next value can be allocated.
+    offset: Cell<usize>,
+    page_size: usize,
+    drops: RefCell<Vec<DropEntry>>,
+}
+
+struct Page {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    /// How many bytes from the start of the page have been handed out,
+    /// updated when the arena moves on to the next page.
+    used: usize,
+}
+
+struct DropEntry {
+    ptr: *mut u8,
+    drop: unsafe fn(*mut u8),
+}
+
+impl ZeroArena {
+    /// Constructs an arena that allocates pages of
+    /// [`DEFAULT_PAGE_SIZE`](./constant.DEFAULT_PAGE_SIZE.html) bytes.
+    ///
+    /// This doesn't allocate until the first value is allocated.
+    pub fn new() -> Self {
+        Self::with_page_size(DEFAULT_PAGE_SIZE)
+    }
+
+    /// Constructs an arena that allocates pages of `page_size` bytes.
+    ///
+    /// Values larger than `page_size` get a page of their own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is zero.
+    pub fn with_page_size(page_size: usize) -> Self {
+        assert!(page_size != 0, "The page size of a ZeroArena can't be zero");
+        Self {
+            pages: RefCell::new(Vec::new()),
+            current: Cell::new(0),
+            offset: Cell::new(0),
+            page_size,
+            drops: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Allocates a zeroed `T`.
+    ///
+    /// The destructor of the value is never run.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_zeroed<T>(&self) -> &mut T
+    where
+        T: Zeroable,
+    {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // Safety: the memory is zeroed,and `T` is `Zeroable`,
+        // the memory isn't handed out again until the arena is reset,
+        // which requires a mutable borrow of the arena.
+        unsafe { &mut *ptr.as_ptr() }
+    }
+
+    /// Allocates a slice of `len` zeroed `T`s.
+    ///
+    /// The destructors of the elements are never run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice overflows `isize`.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_zeroed<T>(&self, len: usize) -> &mut [T]
+    where
+        T: Zeroable,
+    {
+        let layout = Layout::array::<T>(len).expect("The ZeroArena slice is too large");
+        let ptr = self.alloc_layout(layout).cast::<T>();
+        // Safety: same as in `alloc_zeroed`
+        unsafe { slice::from_raw_parts_mut(ptr.as_ptr(), len) }
+    }
+
+    /// Allocates a zeroed `T`,whose destructor runs when the arena is reset or dropped.
+    ///
+    /// Destructors run in the reverse order that the values were allocated in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zeroable::arena::ZeroArena;
+    ///
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static DROPPED: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(zeroable::Zeroable)]
+    /// struct Counted(u32);
+    ///
+    /// impl Drop for Counted {
+    ///     fn drop(&mut self) {
+    ///         DROPPED.fetch_add(1, Ordering::SeqCst);
+    ///     }
+    /// }
+    ///
+    /// let mut arena = ZeroArena::new();
+    ///
+    /// arena.alloc_zeroed::<Counted>();
+    /// arena.alloc_zeroed_with_drop::<Counted>();
+    /// arena.alloc_zeroed_with_drop::<Counted>();
+    ///
+    /// arena.reset();
+    /// assert_eq!(DROPPED.load(Ordering::SeqCst), 2);
+    ///
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_zeroed_with_drop<T>(&self) -> &mut T
+    where
+        T: Zeroable + 'static,
+    {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        if mem::needs_drop::<T>() {
+            self.drops.borrow_mut().push(DropEntry {
+                ptr: ptr.as_ptr() as *mut u8,
+                drop: drop_in_place_erased::<T>,
+            });
+        }
+        // Safety: same as in `alloc_zeroed`
+        unsafe { &mut *ptr.as_ptr() }
+    }
+
+    /// Frees every value allocated in the arena,
+    /// running the destructors of the ones allocated with `alloc_zeroed_with_drop`.
+    ///
+    /// This keeps the pages,zeroing the part of them that was used,
+    /// so that they can be handed out again without allocating.
+    pub fn reset(&mut self) {
+        self.run_drops();
+
+        let pages = self.pages.get_mut();
+        if let Some(page) = pages.get_mut(*self.current.get_mut()) {
+            page.used = cmp::max(page.used, *self.offset.get_mut());
+        }
+        for page in pages.iter_mut() {
+            // Safety: `used` is at most the size of the page,
+            // and there are no references into the page,
+            // since this takes a mutable borrow of the arena.
+            unsafe { ptr::write_bytes(page.ptr.as_ptr(), 0, page.used) }
+            page.used = 0;
+        }
+
+        self.current.set(0);
+        self.offset.set(0);
+    }
+
+    /// The amount of bytes in all the pages allocated by the arena.
+    pub fn allocated_bytes(&self) -> usize {
+        self.pages
+            .borrow()
+            .iter()
+            .map(|page| page.layout.size())
+            .sum()
+    }
+
+    /// The amount of pages allocated by the arena.
+    pub fn page_count(&self) -> usize {
+        self.pages.borrow().len()
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            // Aligned dangling pointers are valid for zero-sized reads and writes.
+            return unsafe { NonNull::new_unchecked(layout.align() as *mut u8) };
+        }
+
+        let mut pages = self.pages.borrow_mut();
+
+        if let Some(page) = pages.get(self.current.get()) {
+            if let Some(start) = page.fit(self.offset.get(), layout) {
+                self.offset.set(start + layout.size());
+                return unsafe { NonNull::new_unchecked(page.ptr.as_ptr().add(start)) };
+            }
+        }
+
+        // The current page doesn't fit the value,moving on to the next page.
+        let next = if pages.is_empty() {
+            0
+        } else {
+            let current = self.current.get();
+            let page = &mut pages[current];
+            page.used = cmp::max(page.used, self.offset.get());
+            current + 1
+        };
+
+        let start = match pages.get(next).and_then(|page| page.fit(0, layout)) {
+            Some(start) => start,
+            None => {
+                pages.insert(next, Page::new(self.page_size, layout));
+                0
+            }
+        };
+
+        self.current.set(next);
+        self.offset.set(start + layout.size());
+        unsafe { NonNull::new_unchecked(pages[next].ptr.as_ptr().add(start)) }
+    }
+
+    fn run_drops(&mut self) {
+        for entry in self.drops.get_mut().drain(..).rev() {
+            // Safety: the entry was pushed with the drop function for the type of the value,
+            // and this is only called once for each value.
+            unsafe { (entry.drop)(entry.ptr) }
+        }
+    }
+}
+
+impl Page {
+    fn new(page_size: usize, layout: Layout) -> Self {
+        let layout = Layout::from_size_align(
+            cmp::max(page_size, layout.size()),
+            cmp::max(PAGE_ALIGN, layout.align()),
+        )
+        .expect("The ZeroArena page is too large");
+
+        // Safety: the layout has a non-zero size,since the value it's allocated for does.
+        let ptr = unsafe { alloc_zeroed(layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Page {
+                ptr,
+                layout,
+                used: 0,
+            },
+            None => handle_alloc_error(layout),
+        }
+    }
+
+    /// Returns the offset that a value with the `layout` layout would start at,
+    /// if it fits in the page after `offset`.
+    fn fit(&self, offset: usize, layout: Layout) -> Option<usize> {
+        let address = (self.ptr.as_ptr() as usize).checked_add(offset)?;
+        let padding = address.wrapping_neg() & (layout.align() - 1);
+        let start = offset.checked_add(padding)?;
+        if start.checked_add(layout.size())? <= self.layout.size() {
+            Some(start)
+        } else {
+            None
+        }
+    }
+}
+
+unsafe fn drop_in_place_erased<T>(ptr: *mut u8) {
+    ptr::drop_in_place(ptr as *mut T)
+}
+
+impl Default for ZeroArena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ZeroArena {
+    fn drop(&mut self) {
+        self.run_drops();
+        for page in self.pages.get_mut().drain(..) {
+            // Safety: the page was allocated with this layout.
+            unsafe { dealloc(page.ptr.as_ptr(), page.layout) }
+        }
+    }
+}
+
+impl fmt::Debug for ZeroArena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZeroArena")
+            .field("page_size", &self.page_size)
+            .field("page_count", &self.page_count())
+            .field("allocated_bytes", &self.allocated_bytes())
+            .finish()
+    }
+}
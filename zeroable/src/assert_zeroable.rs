@@ -2,14 +2,15 @@
 
 use crate::Zeroable;
 
-use bytemuck::Pod;
+use bytemuck::{Pod, TransparentWrapper};
 
 use core::{
     cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
     fmt::{self, Debug},
     hash::{Hash, Hasher},
     marker::PhantomData,
-    mem,
+    mem::{self, ManuallyDrop},
+    num::Wrapping,
 };
 
 /// A marker type representing that `T` is `Zeroable`.
@@ -37,6 +38,127 @@ impl<T> AssertZeroable<T> {
     pub fn zeroed(self) -> T {
         unsafe { mem::zeroed() }
     }
+
+    /// Gets a zeroed `T` in a const context.
+    ///
+    /// This requires the "const_zeroed" feature,which requires Rust 1.75.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zeroable::AssertZeroable;
+    ///
+    /// const ZEROED: (u32, [u8; 3]) = AssertZeroable::NEW.zeroed_const();
+    ///
+    /// assert_eq!( ZEROED, (0, [0, 0, 0]) );
+    ///
+    /// ```
+    #[cfg(feature = "const_zeroed")]
+    #[inline(always)]
+    pub const fn zeroed_const(self) -> T {
+        unsafe { mem::MaybeUninit::zeroed().assume_init() }
+    }
+
+    /// Combines this with an `AssertZeroable<U>` into an `AssertZeroable<(T, U)>`,
+    /// since a tuple of zeroable types is zeroable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zeroable::AssertZeroable;
+    ///
+    /// let pair = AssertZeroable::<u8>::NEW.pair(AssertZeroable::<u32>::NEW);
+    /// assert_eq!( pair.zeroed(), (0, 0) );
+    ///
+    /// let triple = pair.pair(AssertZeroable::<bool>::NEW);
+    /// assert_eq!( triple.zeroed(), ((0, 0), false) );
+    ///
+    /// ```
+    #[inline(always)]
+    pub const fn pair<U>(self, _other: AssertZeroable<U>) -> AssertZeroable<(T, U)> {
+        AssertZeroable(PhantomData)
+    }
+
+    /// Converts this into an `AssertZeroable<[T; N]>`,
+    /// since an array of zeroable types is zeroable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zeroable::AssertZeroable;
+    ///
+    /// let array = AssertZeroable::<u16>::NEW.array::<4>();
+    /// assert_eq!( array.zeroed(), [0, 0, 0, 0] );
+    ///
+    /// ```
+    #[inline(always)]
+    pub const fn array<const N: usize>(self) -> AssertZeroable<[T; N]> {
+        AssertZeroable(PhantomData)
+    }
+
+    /// Converts this into an `AssertZeroable<ManuallyDrop<T>>`,
+    /// since `ManuallyDrop<T>` has the same layout and valid bit patterns as `T`.
+    #[inline(always)]
+    pub const fn manually_drop(self) -> AssertZeroable<ManuallyDrop<T>> {
+        AssertZeroable(PhantomData)
+    }
+
+    /// Converts this into an `AssertZeroable<Wrapping<T>>`,
+    /// since `Wrapping<T>` has the same layout and valid bit patterns as `T`.
+    #[inline(always)]
+    pub const fn wrapping(self) -> AssertZeroable<Wrapping<T>> {
+        AssertZeroable(PhantomData)
+    }
+
+    /// Converts this into an `AssertZeroable<W>`,
+    /// where `W` is a `#[repr(transparent)]` wrapper around `T`.
+    ///
+    /// The safety requirements of `TransparentWrapper` guarantee that
+    /// any valid `T` is also a valid `W`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zeroable::{
+    ///     bytemuck::TransparentWrapper,
+    ///     AssertZeroable,
+    /// };
+    ///
+    /// use core::num::Wrapping;
+    ///
+    /// #[derive(Debug,PartialEq)]
+    /// #[repr(transparent)]
+    /// struct Meters<T>(T);
+    ///
+    /// unsafe impl<T> TransparentWrapper<T> for Meters<T> {}
+    ///
+    /// let meters = AssertZeroable::<f64>::NEW.map_transparent::<Meters<f64>>();
+    /// assert_eq!( meters.zeroed(), Meters(0.0) );
+    ///
+    /// let wrapping = AssertZeroable::<u8>::NEW.map_transparent::<Wrapping<u8>>();
+    /// assert_eq!( wrapping.zeroed(), Wrapping(0) );
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn map_transparent<W>(self) -> AssertZeroable<W>
+    where
+        W: TransparentWrapper<T>,
+    {
+        AssertZeroable(PhantomData)
+    }
+
+    /// Converts this into an `AssertZeroable<I>`,
+    /// where `T` is a `#[repr(transparent)]` wrapper around `I`.
+    ///
+    /// The safety requirements of `TransparentWrapper` guarantee that
+    /// `T` and `I` have the same valid bit patterns.
+    #[inline(always)]
+    pub fn peel_transparent<I>(self) -> AssertZeroable<I>
+    where
+        T: TransparentWrapper<I>,
+    {
+        AssertZeroable(PhantomData)
+    }
 }
 
 unsafe impl<T> Zeroable for AssertZeroable<T> where T: Zeroable {}
@@ -105,3 +227,25 @@ pub trait GetAssertZeroable: Zeroable {
 }
 
 impl<This: ?Sized + Zeroable> GetAssertZeroable for This {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Used by the `assert_not_zeroable` macro to assert that a type is not `Zeroable`.
+///
+/// Both impls of this trait apply to `Zeroable` types,
+/// so referring to `<Type as ExpectedNotZeroable<_>>::item` is ambiguous for them,
+/// causing a compile-time error.
+#[doc(hidden)]
+pub trait ExpectedNotZeroable<Which> {
+    fn item() {}
+}
+
+#[doc(hidden)]
+pub struct IsAnyType;
+
+#[doc(hidden)]
+pub struct IsZeroable;
+
+impl<This: ?Sized> ExpectedNotZeroable<IsAnyType> for This {}
+
+impl<This: Zeroable> ExpectedNotZeroable<IsZeroable> for This {}
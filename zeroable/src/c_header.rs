@@ -0,0 +1,545 @@
+//! Generation of C headers for `#[repr(C)]` types,
+//! with the type definitions,zero initializers,and static assertions on their layout.
+//!
+//! Types opt into this with the
+//! [`#[zero(c_header)]`](../zeroable_docs/index.html#zeroc_header)
+//! attribute of the `Zeroable` derive,
+//! which implements the [`CType`](./trait.CType.html) and
+//! [`CHeaderType`](./trait.CHeaderType.html) traits for them,
+//! and requires Rust 1.77,because it uses `core::mem::offset_of`.
+//!
+//! The [`CHeader`](./struct.CHeader.html) type (which requires the "alloc" feature)
+//! writes the header for the types added to it.
+//!
+//! # Layout
+//!
+//! The sizes,alignments,and field offsets in the header are those of
+//! the platform that the header is generated on,
+//! which is the host in a build script.
+//! When cross-compiling,the static assertions in the header
+//! fail to compile if the layout of the types differs on the target.
+//!
+//! # Example
+//!
+//! This is what a build script that depends on the crate defining the types
+//! (as a build-dependency) would do.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use zeroable::{c_header::CHeader, Zeroable};
+//!
+//! #[derive(Zeroable)]
+//! #[repr(C)]
+//! #[zero(c_header)]
+//! pub struct Point {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! #[derive(Zeroable)]
+//! #[repr(C)]
+//! #[zero(c_header)]
+//! pub struct Shape {
+//!     points: [Point; 4],
+//!     name: *const u8,
+//!     closed: bool,
+//! }
+//!
+//! let header = CHeader::new("SHAPES_H")
+//!     .add::<Shape>()
+//!     .add::<Point>()
+//!     .to_string();
+//!
+//! // In a build script you'd write it to a file:
+//! // header.write_to_file(out_dir.join("shapes.h")).unwrap();
+//!
+//! assert!(header.contains(
+//! "struct Point {
+//!     float x;
+//!     float y;
+//! };
+//!
+//! #define POINT_ZERO_INIT {0}
+//! "));
+//!
+//! assert!(header.contains(
+//! "struct Shape {
+//!     Point points[4];
+//!     const uint8_t *name;
+//!     bool closed;
+//! };
+//!
+//! #define SHAPE_ZERO_INIT {0}
+//! "));
+//!
+//! assert!(header.contains("_Static_assert(sizeof(Point) == 8,"));
+//! assert!(header.contains("_Static_assert(offsetof(Shape, closed) =="));
+//!
+//! // `Point` is defined first,because `Shape` contains it.
+//! assert!(header.find("struct Point {") < header.find("struct Shape {"));
+//! # }
+//! ```
+
+use crate::ffi::ReprC;
+
+use core::{ffi::c_void, fmt};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+extern crate std;
+
+/// For types that have an equivalent C type.
+///
+/// This is implemented for integers,floats,`bool`,`c_void`,raw pointers,arrays,
+/// and for types that use the
+/// [`#[zero(c_header)]`](../zeroable_docs/index.html#zeroc_header)
+/// attribute of the `Zeroable` derive.
+///
+/// # Safety
+///
+/// `C_TYPE` must describe a C type with the same layout as `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::c_header::CType;
+///
+/// assert_eq!(<u64 as CType>::C_TYPE.declaration("len").to_string(), "uint64_t len");
+/// assert_eq!(<*mut [i8; 4] as CType>::C_TYPE.declaration("x").to_string(), "int8_t (*x)[4]");
+/// assert_eq!(
+///     <[*const f32; 2] as CType>::C_TYPE.declaration("xs").to_string(),
+///     "const float *xs[2]",
+/// );
+/// ```
+pub unsafe trait CType: ReprC {
+    /// Describes the equivalent C type.
+    const C_TYPE: CTypeDesc;
+}
+
+macro_rules! impl_c_type {
+    ( $($ty:ty => $c_name:expr),* $(,)* ) => {
+        $(
+            unsafe impl CType for $ty {
+                const C_TYPE: CTypeDesc = CTypeDesc::Named($c_name);
+            }
+        )*
+    };
+}
+
+impl_c_type! {
+    u8 => "uint8_t",
+    i8 => "int8_t",
+    u16 => "uint16_t",
+    i16 => "int16_t",
+    u32 => "uint32_t",
+    i32 => "int32_t",
+    u64 => "uint64_t",
+    i64 => "int64_t",
+    usize => "size_t",
+    isize => "ptrdiff_t",
+    f32 => "float",
+    f64 => "double",
+    bool => "bool",
+    c_void => "void",
+}
+
+unsafe impl ReprC for c_void {}
+
+unsafe impl<T: CType> CType for *const T {
+    const C_TYPE: CTypeDesc = CTypeDesc::Pointer {
+        pointee: &T::C_TYPE,
+        is_const: true,
+    };
+}
+
+unsafe impl<T: CType> CType for *mut T {
+    const C_TYPE: CTypeDesc = CTypeDesc::Pointer {
+        pointee: &T::C_TYPE,
+        is_const: false,
+    };
+}
+
+unsafe impl<T: CType, const N: usize> CType for [T; N] {
+    const C_TYPE: CTypeDesc = CTypeDesc::Array {
+        element: &T::C_TYPE,
+        len: N,
+    };
+}
+
+/// Describes a C type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CTypeDesc {
+    /// A type referred to by name,eg:`uint32_t`,
+    /// or the name of a type with the `#[zero(c_header)]` attribute.
+    Named(&'static str),
+    /// A pointer to `pointee`,
+    /// `is_const` is true for `*const` pointers.
+    Pointer {
+        /// The type being pointed to.
+        pointee: &'static CTypeDesc,
+        /// Whether the pointee is `const`.
+        is_const: bool,
+    },
+    /// An array of `len` `element`s.
+    Array {
+        /// The type of the elements.
+        element: &'static CTypeDesc,
+        /// The amount of elements.
+        len: usize,
+    },
+}
+
+impl CTypeDesc {
+    /// Gets a C declaration of `declarator` with this type,
+    /// eg:`uint8_t name[16]` for `[u8; 16]`.
+    pub fn declaration<'a>(&'a self, declarator: &'a str) -> CDeclaration<'a> {
+        CDeclaration {
+            ty: self,
+            declarator,
+        }
+    }
+
+    /// The name of the type that this is,or contains by value (through arrays).
+    ///
+    /// This returns `None` for pointers.
+    pub fn by_value_name(&self) -> Option<&'static str> {
+        match *self {
+            CTypeDesc::Named(name) => Some(name),
+            CTypeDesc::Pointer { .. } => None,
+            CTypeDesc::Array { element, .. } => element.by_value_name(),
+        }
+    }
+
+    fn write_declaration(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        is_const: bool,
+        declarator: &dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+        declarator_is_pointer: bool,
+    ) -> fmt::Result {
+        match *self {
+            CTypeDesc::Named(name) => {
+                if is_const {
+                    f.write_str("const ")?;
+                }
+                write!(f, "{} ", name)?;
+                declarator(f)
+            }
+            CTypeDesc::Pointer {
+                pointee,
+                is_const: pointee_is_const,
+            } => {
+                let pointer = |f: &mut fmt::Formatter<'_>| {
+                    f.write_str(if is_const { "*const " } else { "*" })?;
+                    declarator(f)
+                };
+                pointee.write_declaration(f, pointee_is_const, &pointer, true)
+            }
+            CTypeDesc::Array { element, len } => {
+                // Arrays bind tighter than pointers in C declarators,
+                // so pointers to arrays need parentheses.
+                let array = |f: &mut fmt::Formatter<'_>| {
+                    if declarator_is_pointer {
+                        f.write_str("(")?;
+                        declarator(f)?;
+                        f.write_str(")")?;
+                    } else {
+                        declarator(f)?;
+                    }
+                    write!(f, "[{}]", len)
+                };
+                element.write_declaration(f, is_const, &array, false)
+            }
+        }
+    }
+}
+
+/// A C declaration,returned by [`CTypeDesc::declaration`](./enum.CTypeDesc.html#method.declaration).
+///
+/// This is formatted with its `Display` impl.
+#[derive(Debug, Copy, Clone)]
+pub struct CDeclaration<'a> {
+    ty: &'a CTypeDesc,
+    declarator: &'a str,
+}
+
+impl fmt::Display for CDeclaration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ty
+            .write_declaration(f, false, &|f| f.write_str(self.declarator), false)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// For types that can be written to a C header with [`CHeader`](./struct.CHeader.html).
+///
+/// This is implemented by the `Zeroable` derive
+/// when the [`#[zero(c_header)]`](../zeroable_docs/index.html#zeroc_header)
+/// attribute is used.
+pub trait CHeaderType: CType {
+    /// The definition of the type,with its layout.
+    const C_DEFINITION: CDefinition;
+}
+
+/// The definition of a C struct or union,with the layout of the Rust type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CDefinition {
+    /// The name of the type.
+    pub name: &'static str,
+    /// Whether this is a struct or a union.
+    pub kind: CKind,
+    /// The size in bytes of the type.
+    pub size: usize,
+    /// The alignment in bytes of the type,
+    /// which is larger than that of its fields for `#[repr(align(...))]` types.
+    pub align: usize,
+    /// The fields of the type,in declaration order.
+    pub fields: &'static [CField],
+}
+
+/// Whether a [`CDefinition`](./struct.CDefinition.html) is a struct or a union.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CKind {
+    /// A struct
+    Struct,
+    /// A union
+    Union,
+}
+
+/// A field of a [`CDefinition`](./struct.CDefinition.html).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CField {
+    /// The name of the field,which is its position prefixed with `_` for tuple structs.
+    pub name: &'static str,
+    /// The type of the field.
+    pub ty: CTypeDesc,
+    /// The offset in bytes of the field from the start of the type.
+    pub offset: usize,
+    /// The alignment in bytes of the type of the field.
+    pub align: usize,
+}
+
+impl CKind {
+    /// The C keyword for this kind of type.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            CKind::Struct => "struct",
+            CKind::Union => "union",
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builds a C header with the definitions of [`CHeaderType`](./trait.CHeaderType.html) types.
+///
+/// For each type,the header contains:
+///
+/// - A `typedef` so that it can be referred to without the `struct`/`union` keyword.
+///
+/// - Its definition.
+///
+/// - A `#define NAME_ZERO_INIT {0}` zero initializer,
+///   where `NAME` is the name of the type in SCREAMING_SNAKE_CASE.
+///
+/// - `_Static_assert`s that its size,alignment,and the offset of its fields
+///   match the Rust type.
+///
+/// Types are defined after the types that their fields contain by value,
+/// and the `typedef`s come before every definition,
+/// so that pointers can refer to types defined later.
+///
+/// The types of the fields that aren't added to the header must be defined by
+/// one of the headers that it includes,
+/// which are `stdbool.h`,`stddef.h`,and `stdint.h`.
+///
+/// The header uses C11 features (`_Static_assert` and `_Alignof`).
+///
+/// For an example,look at the [module-level documentation](./index.html#example).
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CHeader {
+    include_guard: String,
+    definitions: Vec<CDefinition>,
+}
+
+#[cfg(feature = "alloc")]
+impl CHeader {
+    /// Constructs an empty header,with `include_guard` as the name of its include guard macro.
+    pub fn new(include_guard: &str) -> Self {
+        Self {
+            include_guard: include_guard.into(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Adds the definition of `T` to the header.
+    ///
+    /// Adding the same type multiple times only defines it once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a different type with the same name was already added.
+    pub fn add<T>(&mut self) -> &mut Self
+    where
+        T: CHeaderType,
+    {
+        let def = T::C_DEFINITION;
+        match self.definitions.iter().find(|x| x.name == def.name) {
+            Some(prev) if *prev == def => {}
+            Some(_) => panic!("Two different types named `{}` were added", def.name),
+            None => self.definitions.push(def),
+        }
+        self
+    }
+
+    /// Writes the header to the `path` file,
+    /// doing nothing if the file already has the same contents,
+    /// to avoid needlessly rebuilding the C code that includes it.
+    ///
+    /// This requires the "std" feature.
+    #[cfg(feature = "std")]
+    pub fn write_to_file<P>(&self, path: P) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        use alloc::string::ToString;
+
+        let path = path.as_ref();
+        let header = self.to_string();
+        match std::fs::read_to_string(path) {
+            Ok(prev) if prev == header => Ok(()),
+            _ => std::fs::write(path, header),
+        }
+    }
+
+    /// Gets the definitions in the order they're written in,
+    /// where types come after the types that they contain by value.
+    fn sorted_definitions(&self) -> Vec<&CDefinition> {
+        fn visit<'a>(
+            index: usize,
+            defs: &'a [CDefinition],
+            visited: &mut [bool],
+            sorted: &mut Vec<&'a CDefinition>,
+        ) {
+            if visited[index] {
+                return;
+            }
+            visited[index] = true;
+            for field in defs[index].fields {
+                let dependency = field
+                    .ty
+                    .by_value_name()
+                    .and_then(|name| defs.iter().position(|def| def.name == name));
+                if let Some(dependency) = dependency {
+                    visit(dependency, defs, visited, sorted);
+                }
+            }
+            sorted.push(&defs[index]);
+        }
+
+        let defs = &*self.definitions;
+        let mut visited = alloc::vec![false; defs.len()];
+        let mut sorted = Vec::with_capacity(defs.len());
+        for index in 0..defs.len() {
+            visit(index, defs, &mut visited, &mut sorted);
+        }
+        sorted
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let guard = &self.include_guard;
+
+        writeln!(
+            f,
+            "/* Generated by the zeroable crate,don't edit this file. */"
+        )?;
+        writeln!(f)?;
+        writeln!(f, "#ifndef {}", guard)?;
+        writeln!(f, "#define {}", guard)?;
+        writeln!(f)?;
+        writeln!(f, "#include <stdbool.h>")?;
+        writeln!(f, "#include <stddef.h>")?;
+        writeln!(f, "#include <stdint.h>")?;
+        writeln!(f)?;
+
+        let defs = self.sorted_definitions();
+
+        for def in &defs {
+            writeln!(f, "typedef {0} {1} {1};", def.kind.keyword(), def.name)?;
+        }
+
+        for def in &defs {
+            let name = def.name;
+
+            writeln!(f)?;
+            writeln!(f, "{} {} {{", def.kind.keyword(), name)?;
+            // Over-aligned types (from `#[repr(align(...))]`) get their alignment
+            // from an `_Alignas` on the first field.
+            let field_align = def.fields.iter().map(|x| x.align).max().unwrap_or(1);
+            for (i, field) in def.fields.iter().enumerate() {
+                f.write_str("    ")?;
+                if i == 0 && def.align > field_align {
+                    write!(f, "_Alignas({}) ", def.align)?;
+                }
+                writeln!(f, "{};", field.ty.declaration(field.name))?;
+            }
+            writeln!(f, "}};")?;
+            writeln!(f)?;
+            writeln!(f, "#define {}_ZERO_INIT {{0}}", ScreamingSnake(name))?;
+            writeln!(f)?;
+            writeln!(
+                f,
+                "_Static_assert(sizeof({0}) == {1}, \"The size of `{0}` doesn't match the Rust type\");",
+                name, def.size,
+            )?;
+            writeln!(
+                f,
+                "_Static_assert(_Alignof({0}) == {1}, \
+                 \"The alignment of `{0}` doesn't match the Rust type\");",
+                name, def.align,
+            )?;
+            for field in def.fields {
+                writeln!(
+                    f,
+                    "_Static_assert(offsetof({0}, {1}) == {2}, \
+                     \"The offset of `{0}.{1}` doesn't match the Rust type\");",
+                    name, field.name, field.offset,
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(f, "#endif /* {} */", guard)
+    }
+}
+
+/// Formats a CamelCase name in SCREAMING_SNAKE_CASE.
+#[cfg(feature = "alloc")]
+struct ScreamingSnake<'a>(&'a str);
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ScreamingSnake<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use core::fmt::Write;
+
+        let mut prev_is_lower = false;
+        for c in self.0.chars() {
+            if c.is_uppercase() && prev_is_lower {
+                f.write_char('_')?;
+            }
+            prev_is_lower = c.is_lowercase() || c.is_ascii_digit();
+            for upper in c.to_uppercase() {
+                f.write_char(upper)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+//! Helpers for calling C functions that take pointers to zeroed out-parameters,
+//! the `ReprC` trait for types with a C-compatible layout,
+//! and the `SizeField` trait used by the `new_zeroed` constructor of the `Zeroable` derive.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zeroable::{ffi::with_zeroed_out, Zeroable};
+//!
+//! #[derive(Debug, PartialEq, Zeroable)]
+//! #[repr(C)]
+//! struct Version {
+//!     major: u32,
+//!     minor: u32,
+//!     flags: u64,
+//! }
+//!
+//! // Stands in for a C function declared in an `extern "C"` block.
+//! unsafe extern "C" fn get_version(out: *mut Version) -> i32 {
+//!     (*out).major = 1;
+//!     (*out).minor = 4;
+//!     0
+//! }
+//!
+//! let (status, version) = with_zeroed_out(|out| unsafe { get_version(out) });
+//!
+//! assert_eq!(status, 0);
+//! assert_eq!(version, Version { major: 1, minor: 4, flags: 0 });
+//!
+//! ```
+
+use crate::{zero_in_place, Zeroable};
+
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, ops::Deref};
+
+/// For types with a C-compatible layout.
+///
+/// The `Zeroable` derive implements this trait for
+/// `#[repr(C)]` types,and for enums with an integer representation (eg:`#[repr(u8)]`),
+/// when the types of all their fields implement `ReprC`.
+///
+/// This is also implemented for integers,floats,`bool`,raw pointers,
+/// and arrays of `ReprC` types.
+///
+/// # Safety
+///
+/// Implementors must have a layout that C code can rely on,
+/// which for structs,unions,and enums requires a `#[repr(C)]` attribute
+/// (or an integer repr for enums).
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{ffi::ReprC, Zeroable};
+///
+/// fn assert_repr_c<T: ReprC>() {}
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct Rect {
+///     size: [u32; 2],
+///     origin: [i32; 2],
+/// }
+///
+/// assert_repr_c::<Rect>();
+/// assert_repr_c::<[*const Rect; 4]>();
+/// ```
+pub unsafe trait ReprC {}
+
+macro_rules! impl_repr_c {
+    ( $($ty:ty),* $(,)* ) => {
+        $( unsafe impl ReprC for $ty {} )*
+    };
+}
+
+impl_repr_c! {
+    u8, i8, u16, i16, u32, i32, u64, i64, usize, isize,
+    f32, f64, bool,
+}
+
+unsafe impl<T> ReprC for *const T {}
+
+unsafe impl<T> ReprC for *mut T {}
+
+unsafe impl<T: ReprC, const N: usize> ReprC for [T; N] {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// For the integer types that the field of a
+/// [`#[zero(size_field="...")]`](../zeroable_docs/index.html#zerosize_fieldfield_name)
+/// attribute can have.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::ffi::SizeField;
+///
+/// assert_eq!(u32::from_size(24), 24_u32);
+/// assert_eq!(u16::from_size(0x1000), 0x1000_u16);
+/// ```
+pub trait SizeField: Sized {
+    /// Converts the size of a type to `Self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` doesn't fit in `Self`.
+    fn from_size(size: usize) -> Self;
+}
+
+macro_rules! impl_size_field {
+    ( $($ty:ty),* $(,)* ) => {
+        $(
+            impl SizeField for $ty {
+                #[inline]
+                fn from_size(size: usize) -> Self {
+                    match core::convert::TryFrom::try_from(size) {
+                        Ok(x) => x,
+                        Err(_) => panic!(
+                            "The size ({}) doesn't fit in the `{}` size field",
+                            size,
+                            stringify!($ty),
+                        ),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_size_field! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Calls `f` with a pointer to a zeroed `T`,
+/// returning what `f` returned along with the `T`.
+///
+/// This is for calling C functions that take an out-parameter,
+/// and expect it to be zeroed beforehand.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{ffi::with_zeroed_out, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct Stats {
+///     reads: u64,
+///     writes: u64,
+/// }
+///
+/// unsafe extern "C" fn get_stats(out: *mut Stats) {
+///     (*out).reads += 10;
+/// }
+///
+/// let ((), stats) = with_zeroed_out(|out| unsafe { get_stats(out) });
+/// assert_eq!(stats.reads, 10);
+/// assert_eq!(stats.writes, 0);
+///
+/// ```
+#[inline]
+pub fn with_zeroed_out<T, F, R>(f: F) -> (R, T)
+where
+    T: Zeroable + ReprC,
+    F: FnOnce(*mut T) -> R,
+{
+    let mut value = T::zeroed();
+    let ret = f(&mut value);
+    (ret, value)
+}
+
+/// Calls `f` with a pointer to the first element of a zeroed `[T; N]`,and its length,
+/// returning what `f` returned along with the array.
+///
+/// This is for calling C functions that take a pointer to an array of out-parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{ffi::with_zeroed_out_array, Zeroable};
+///
+/// #[derive(Debug, PartialEq, Zeroable)]
+/// #[repr(C)]
+/// struct Event {
+///     kind: u32,
+///     code: u32,
+/// }
+///
+/// unsafe extern "C" fn poll_events(out: *mut Event, capacity: usize) -> usize {
+///     let events = std::slice::from_raw_parts_mut(out, capacity);
+///     events[0].kind = 3;
+///     events[1].code = 7;
+///     2
+/// }
+///
+/// let (count, events) = with_zeroed_out_array::<Event, _, _, 4>(|out, capacity| unsafe {
+///     poll_events(out, capacity)
+/// });
+///
+/// assert_eq!(count, 2);
+/// assert_eq!(events[0], Event { kind: 3, code: 0 });
+/// assert_eq!(events[1], Event { kind: 0, code: 7 });
+/// assert_eq!(events[3], Event { kind: 0, code: 0 });
+///
+/// ```
+#[inline]
+pub fn with_zeroed_out_array<T, F, R, const N: usize>(f: F) -> (R, [T; N])
+where
+    T: Zeroable + ReprC,
+    F: FnOnce(*mut T, usize) -> R,
+{
+    // Safety: an array of `Zeroable` types is valid when zeroed.
+    let mut array = unsafe { MaybeUninit::<[T; N]>::zeroed().assume_init() };
+    let ret = f(array.as_mut_ptr(), N);
+    (ret, array)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A zeroed out-parameter,which dereferences to a `*mut T` pointing to it.
+///
+/// This is constructed from a mutable reference,zeroing the referenced value,
+/// so that the same value can be reused as the out-parameter of multiple calls.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{ffi::ZeroedOut, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct Reading {
+///     sensor: u32,
+///     value: i32,
+/// }
+///
+/// unsafe extern "C" fn read_sensor(sensor: u32, out: *mut Reading) {
+///     (*out).sensor = sensor;
+///     (*out).value -= 5;
+/// }
+///
+/// let mut reading = Reading::zeroed();
+///
+/// for sensor in 1..4 {
+///     let out = ZeroedOut::new(&mut reading);
+///     unsafe { read_sensor(sensor, *out) };
+///
+///     let reading = out.into_mut();
+///     assert_eq!(reading.sensor, sensor);
+///     assert_eq!(reading.value, -5);
+/// }
+///
+/// ```
+pub struct ZeroedOut<'a, T> {
+    ptr: *mut T,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ZeroedOut<'a, T>
+where
+    T: Zeroable + ReprC,
+{
+    /// Zeroes `value` in place (with [`zero_in_place`](../fn.zero_in_place.html)),
+    /// and constructs a `ZeroedOut` pointing to it.
+    #[inline]
+    pub fn new(value: &'a mut T) -> Self {
+        zero_in_place(value);
+        Self {
+            ptr: value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the pointer to the out-parameter.
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.ptr
+    }
+
+    /// Gets back the mutable reference that this was constructed from,
+    /// to read the value written through the pointer.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut T {
+        // Safety: the pointer was constructed from a `&'a mut T`.
+        unsafe { &mut *self.ptr }
+    }
+}
+
+impl<'a, T> Deref for ZeroedOut<'a, T> {
+    type Target = *mut T;
+
+    #[inline]
+    fn deref(&self) -> &*mut T {
+        &self.ptr
+    }
+}
+
+impl<'a, T> fmt::Debug for ZeroedOut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ZeroedOut").field(&self.ptr).finish()
+    }
+}
@@ -0,0 +1,273 @@
+//! Contains the `IsZero` trait,and the `is_zeroed`/`is_zeroed_slice` functions.
+
+use bytemuck::NoUninit;
+
+use core::{
+    cell::Cell,
+    marker::{PhantomData, PhantomPinned},
+    mem::ManuallyDrop,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    },
+    ptr::NonNull,
+};
+
+/// For checking whether a value is equal to the zeroed value of its type,
+/// without reading padding bytes.
+///
+/// # Derive
+///
+/// This trait can be derived for structs and enums.
+///
+/// For structs,every field is compared with [`IsZero::is_zero`].
+///
+/// For enums,the value is zero when it's the variant that `Zeroable::zeroed` returns,
+/// and all the fields of that variant are zero.
+/// The enum has to have a zero variant,with the same rules as
+/// [the `Zeroable` derive](../zeroable_docs/index.html#enums).
+///
+/// Every type parameter is required to implement `IsZero`,
+/// unless it's listed in a `#[zero(not_zeroable(...))]` attribute.
+/// `#[zero(bound="...")]` attributes can be used to add more bounds to the impl.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{IsZero, Zeroable};
+///
+/// // This type has padding between the fields,so it can't use `is_zeroed`.
+/// #[derive(Zeroable, IsZero)]
+/// #[repr(C)]
+/// struct Sample {
+///     channel: u8,
+///     value: f32,
+/// }
+///
+/// #[derive(Zeroable, IsZero)]
+/// #[repr(u8)]
+/// enum Delta {
+///     Unchanged,
+///     Changed(Sample),
+/// }
+///
+/// assert!( Sample::zeroed().is_zero() );
+/// assert!( !Sample{ channel: 1, value: 0.0 }.is_zero() );
+///
+/// assert!( Delta::zeroed().is_zero() );
+/// assert!( !Delta::Changed(Sample::zeroed()).is_zero() );
+///
+/// ```
+pub trait IsZero {
+    /// Whether `self` is equal to the zeroed value of the type.
+    fn is_zero(&self) -> bool;
+}
+
+/// Whether all the bytes of `value` are zero.
+///
+/// The bytes are compared in word-sized chunks.
+///
+/// For types with padding,use the [`IsZero`](./trait.IsZero.html) trait instead.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::is_zeroed;
+///
+/// assert!( is_zeroed(&[0_u32; 16]) );
+/// assert!( is_zeroed(&0.0_f64) );
+/// assert!( !is_zeroed(&[0, 0, 0, 1_u8]) );
+///
+/// // Negative zero is not all zero bytes.
+/// assert!( !is_zeroed(&-0.0_f32) );
+///
+/// ```
+#[inline]
+pub fn is_zeroed<T>(value: &T) -> bool
+where
+    T: NoUninit,
+{
+    bytes_are_zeroed(bytemuck::bytes_of(value))
+}
+
+/// Whether all the bytes of `slice` are zero.
+///
+/// The bytes are compared in word-sized chunks.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::is_zeroed_slice;
+///
+/// let mut samples = vec![0_u16; 1000];
+/// assert!( is_zeroed_slice(&samples) );
+///
+/// samples[999] = 5;
+/// assert!( !is_zeroed_slice(&samples) );
+/// assert!( is_zeroed_slice(&samples[..999]) );
+///
+/// ```
+#[inline]
+pub fn is_zeroed_slice<T>(slice: &[T]) -> bool
+where
+    T: NoUninit,
+{
+    bytes_are_zeroed(bytemuck::cast_slice(slice))
+}
+
+fn bytes_are_zeroed(bytes: &[u8]) -> bool {
+    // Every bit pattern is a valid `usize`,so this is sound.
+    let (prefix, words, suffix) = unsafe { bytes.align_to::<usize>() };
+
+    prefix.iter().all(|&b| b == 0)
+        && words.iter().all(|&w| w == 0)
+        && suffix.iter().all(|&b| b == 0)
+}
+
+macro_rules! impl_is_zero {
+    ( eq_zero[$($ty:ty => $zero:expr),* $(,)?] ) => {
+        $(
+            impl IsZero for $ty {
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+        )*
+    };
+    ( is_none[$($ty:ty),* $(,)?] ) => {
+        $(
+            impl IsZero for Option<$ty> {
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    self.is_none()
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero! {
+    eq_zero[
+        u8 => 0, i8 => 0, u16 => 0, i16 => 0, u32 => 0, i32 => 0,
+        u64 => 0, i64 => 0, u128 => 0, i128 => 0, usize => 0, isize => 0,
+        bool => false,
+        char => '\0',
+        () => (),
+        PhantomPinned => PhantomPinned,
+    ]
+}
+
+impl_is_zero! {
+    is_none[
+        NonZeroU8, NonZeroI8, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32,
+        NonZeroU64, NonZeroI64, NonZeroU128, NonZeroI128, NonZeroUsize, NonZeroIsize,
+    ]
+}
+
+// Floats are compared by their bits,because `-0.0 == 0.0`.
+impl IsZero for f32 {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+impl IsZero for f64 {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+impl<T: ?Sized> IsZero for PhantomData<T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> IsZero for *const T {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_null()
+    }
+}
+
+impl<T: ?Sized> IsZero for *mut T {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_null()
+    }
+}
+
+impl<T: ?Sized> IsZero for Option<&T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T: ?Sized> IsZero for Option<&mut T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T: ?Sized> IsZero for Option<NonNull<T>> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T: IsZero, const N: usize> IsZero for [T; N] {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.iter().all(T::is_zero)
+    }
+}
+
+impl<T: IsZero> IsZero for Wrapping<T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: IsZero> IsZero for ManuallyDrop<T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        (**self).is_zero()
+    }
+}
+
+impl<T: IsZero + Copy> IsZero for Cell<T> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.get().is_zero()
+    }
+}
+
+macro_rules! impl_is_zero_tuples {
+    ( $( ($($tparam:ident = $index:tt),*) )* ) => {
+        $(
+            impl<$($tparam: IsZero),*> IsZero for ($($tparam,)*) {
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    true $( && self.$index.is_zero() )*
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero_tuples! {
+    (A = 0)
+    (A = 0, B = 1)
+    (A = 0, B = 1, C = 2)
+    (A = 0, B = 1, C = 2, D = 3)
+    (A = 0, B = 1, C = 2, D = 3, E = 4)
+    (A = 0, B = 1, C = 2, D = 3, E = 4, F = 5)
+}
@@ -0,0 +1,67 @@
+//! Contains the `ZeroableLayout` trait,which describes the fields of a type,
+//! implemented with the `#[zero(layout_info)]` attribute of the `Zeroable` derive.
+
+/// Describes the layout of the fields of a struct or union.
+///
+/// This is implemented by the `Zeroable` derive
+/// when the `#[zero(layout_info)]` attribute is used.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{
+///     layout::{FieldLayout, ZeroableLayout},
+///     Zeroable,
+/// };
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(layout_info)]
+/// union Slot {
+///     value: u32,
+///     #[zero(nonzero)]
+///     name: &'static str,
+/// }
+///
+/// assert_eq!(
+///     Slot::ZEROABLE_LAYOUT,
+///     &[
+///         FieldLayout {
+///             name: "value",
+///             type_name: "u32",
+///             offset: 0,
+///             size: 4,
+///             zeroable: true,
+///         },
+///         FieldLayout {
+///             name: "name",
+///             type_name: "& 'static str",
+///             offset: 0,
+///             size: core::mem::size_of::<&str>(),
+///             zeroable: false,
+///         },
+///     ][..],
+/// );
+///
+/// ```
+pub trait ZeroableLayout {
+    /// The fields of the type,in declaration order.
+    const ZEROABLE_LAYOUT: &'static [FieldLayout];
+}
+
+/// Describes a field of a type that implements `ZeroableLayout`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    /// The name of the field,which is its position for tuple structs.
+    pub name: &'static str,
+    /// The type of the field,as written in the type definition.
+    pub type_name: &'static str,
+    /// The offset in bytes of the field from the start of the type.
+    pub offset: usize,
+    /// The size in bytes of the field.
+    pub size: usize,
+    /// Whether the field is required to be zeroable.
+    ///
+    /// This is false for the fields with the `#[zero(nonzero)]` attribute.
+    pub zeroable: bool,
+}
@@ -89,13 +89,32 @@ unsafe{
     Slightly improved debugging,
     shows the type of `T` in `AssertZeroable<T>`'s ' Debug implementation
 
+- "const_zeroed":
+    Enables the `AssertZeroable::zeroed_const` method and the `zeroed_static` macro,
+    which require Rust 1.75.
+
+- "alloc":
+    Enables the `arena` module,with the `ZeroArena` bump allocator,
+    the `pool` module,with the `ZeroPool` object pool,
+    the `zeroed_vec` module,with the `ZeroedVec` growable array,
+    and the `c_header::CHeader` C header generator,
+    which use the `alloc` crate.
+
+- "std":
+    Enables the "alloc" feature,the `pool::SyncZeroPool` type,
+    which uses `std::sync::Mutex`,
+    and the `CHeader::write_to_file` method.
+    Requires Rust 1.63.
+
 - "nightly_docs":
     Makes the documentation examples that require Rust nightly run in doctests,
     and shows them as tested in the documentation.
 
 # `#[no_std]` support
 
-This crate is `#[no_std]`,and only requires the `core` library.
+This crate is `#[no_std]`,and only requires the `core` library,
+unless the "alloc" or "std" features are enabled,
+which require the `alloc` and `std` libraries respectively.
 
 
 */
@@ -105,10 +124,42 @@ This crate is `#[no_std]`,and only requires the `core` library.
 #![cfg_attr(feature = "nightly_testing", feature(transparent_unions))]
 #![cfg_attr(feature = "nightly_testing", feature(transparent_enums))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "alloc")]
+pub mod arena;
+
 pub mod assert_zeroable;
 
+pub mod c_header;
+
+pub mod ffi;
+
+pub mod is_zero;
+
+pub mod layout;
+
+pub mod maybe_zeroable;
+
+#[cfg(feature = "alloc")]
+pub mod pool;
+
+pub mod reset;
+
+pub mod zero_page;
+
+#[cfg(feature = "const_zeroed")]
+pub mod statics;
+
 pub mod zeroable_docs;
 
+#[cfg(feature = "alloc")]
+pub mod zeroed_vec;
+
 extern crate self as zeroable;
 
 #[doc(noinline)]
@@ -120,10 +171,18 @@ pub use bytemuck;
 ///
 pub use bytemuck::Zeroable;
 
-pub use zeroable_derive::Zeroable;
+pub use zeroable_derive::{IsZero, Reset, Zeroable};
 
 pub use crate::assert_zeroable::{AssertZeroable, GetAssertZeroable};
 
+pub use crate::is_zero::{is_zeroed, is_zeroed_slice, IsZero};
+
+pub use crate::layout::{FieldLayout, ZeroableLayout};
+
+pub use crate::reset::{zero_in_place, Reset};
+
+pub use crate::zero_page::{zeroed_ref, zeroed_slice_ref};
+
 #[cfg(all(test, not(feature = "testing")))]
 compile_error! { "tests must be run with the \"testing\" feature" }
 
@@ -0,0 +1,324 @@
+/// Derives `Zeroable` for a mirror of a type defined in another crate
+/// (usually a `-sys` crate),
+/// checking that the layout of the mirror matches the layout of the foreign type.
+///
+/// This is equivalent to using `#[derive(Zeroable)]` on the mirror type,
+/// which must have a `#[zero(remote="path::to::ForeignType")]` attribute.
+///
+/// For more details,look at
+/// [the `remote` attribute documentation](./zeroable_docs/index.html#zeroremotepathtotype).
+///
+/// Using this macro requires Rust 1.77,because it uses `core::mem::offset_of`.
+///
+/// # Example
+///
+/// ```rust
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct timeval {
+///         pub tv_sec: i64,
+///         pub tv_usec: i64,
+///     }
+/// }
+///
+/// zeroable::remote!{
+///     #[zero(remote="ffi::timeval", remote_newtype="Timeval")]
+///     #[repr(C)]
+///     pub struct TimevalMirror {
+///         pub tv_sec: i64,
+///         pub tv_usec: i64,
+///     }
+/// }
+///
+/// use zeroable::Zeroable;
+///
+/// let zeroed = Timeval::zeroed().0;
+/// assert_eq!( (zeroed.tv_sec, zeroed.tv_usec), (0, 0) );
+///
+/// ```
+#[macro_export]
+macro_rules! remote {
+    ( $($type_definition:tt)* ) => {
+        #[derive($crate::Zeroable)]
+        $($type_definition)*
+    };
+}
+
+/// Asserts at compile-time that the types implement `Zeroable`.
+///
+/// This expands to an item,
+/// so it can be used anywhere an item can (including inside functions and constants).
+///
+/// # Generic types
+///
+/// To assert that a generic type implements `Zeroable` for all the
+/// type parameters that satisfy some bounds,
+/// use the `impl[<generic_params>] <type> where <predicates>` syntax.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{assert_zeroable, Zeroable};
+///
+/// use core::num::Wrapping;
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_zeroable!(u8, Foo<u8>, [Foo<u32>; 4]);
+///
+/// assert_zeroable!(impl[T] Foo<Wrapping<T>> where T: Zeroable);
+///
+/// const _: () = {
+///     assert_zeroable!(Foo<(u8, u16)>);
+/// };
+///
+/// ```
+///
+/// This doesn't compile because `&str` isn't zeroable:
+///
+/// ```compile_fail
+/// use zeroable::{assert_zeroable, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[zero(not_zeroable(T))]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_zeroable!(Foo<&'static str>);
+/// ```
+#[macro_export]
+macro_rules! assert_zeroable {
+    ( impl[$($generics:tt)*] $type:ty $(where $($where_preds:tt)* )? ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_zeroable<$($generics)*>()
+            $(where $($where_preds)*)?
+            {
+                let _ = <$type as $crate::GetAssertZeroable>::GET;
+            }
+        };
+    };
+    ( $($type:ty),* $(,)? ) => {
+        const _: () = {
+            $( let _ = <$type as $crate::GetAssertZeroable>::GET; )*
+        };
+    };
+}
+
+/// Asserts at compile-time that the types don't implement `Zeroable`.
+///
+/// This expands to an item,
+/// so it can be used anywhere an item can (including inside functions and constants).
+///
+/// The error message when a type implements `Zeroable` mentions that
+/// the `ExpectedNotZeroable` trait is implemented multiple times for the type.
+///
+/// # Generic types
+///
+/// To assert that a generic type isn't provably `Zeroable` under some bounds,
+/// use the `impl[<generic_params>] <type> where <predicates>` syntax.
+///
+/// The generic form only asserts that the bounds don't imply that the type is `Zeroable`,
+/// it still passes if the type is `Zeroable` for some of the type arguments
+/// that satisfy the bounds.
+/// For example,`assert_not_zeroable!(impl[T] Foo<T>)` passes when `Foo<T>`
+/// is `Zeroable` only if `T: Zeroable`,
+/// while `assert_not_zeroable!(impl[T: Zeroable] Foo<T>)` fails to compile.
+/// To assert that specific instantiations aren't `Zeroable`,
+/// pass the concrete types instead (eg:`Foo<NonZeroU8>`).
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{assert_not_zeroable, Zeroable};
+///
+/// use core::num::NonZeroU8;
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_not_zeroable!(NonZeroU8, Foo<NonZeroU8>, Foo<&'static str>, &'static str);
+///
+/// // `Foo<T>` is only zeroable if `T` is zeroable.
+/// assert_not_zeroable!(impl[T] Foo<T>);
+///
+/// assert_not_zeroable!(impl[T] Foo<T> where T: Clone);
+///
+/// ```
+///
+/// This doesn't compile because `u8` is zeroable:
+///
+/// ```compile_fail
+/// use zeroable::{assert_not_zeroable, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_not_zeroable!(Foo<u8>);
+/// ```
+#[macro_export]
+macro_rules! assert_not_zeroable {
+    ( impl[$($generics:tt)*] $type:ty $(where $($where_preds:tt)* )? ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_not_zeroable<$($generics)*>()
+            $(where $($where_preds)*)?
+            {
+                let _ = <$type as $crate::assert_zeroable::ExpectedNotZeroable<_>>::item;
+            }
+        };
+    };
+    ( $($type:ty),* $(,)? ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_not_zeroable() {
+                $( let _ = <$type as $crate::assert_zeroable::ExpectedNotZeroable<_>>::item; )*
+            }
+        };
+    };
+}
+
+/// Evaluates to `Some(AssertZeroable<Type>)` if `Type` implements `Zeroable`,
+/// otherwise evaluates to `None`.
+///
+/// This allows code without a `Zeroable` bound to take a fast path
+/// (eg:bulk zeroing,`alloc_zeroed`) for types that happen to be zeroable.
+///
+/// # Limitations
+///
+/// Whether `Type` is `Zeroable` is decided where the macro is invoked,
+/// using the bounds in scope at that point.
+///
+/// Inside generic functions this means that for a type parameter `T`:
+///
+/// - `maybe_zeroable!(T)` evaluates to `Some` when there's a `T: Zeroable` bound.
+///
+/// - `maybe_zeroable!(T)` evaluates to `None` otherwise,
+///   even if the function is called with a `Zeroable` type.
+///
+/// So this is most useful inside other macros,
+/// which are expanded with the concrete type.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{maybe_zeroable, AssertZeroable};
+///
+/// use core::num::NonZeroU8;
+///
+/// assert_eq!( maybe_zeroable!(u32), Some(AssertZeroable::<u32>::NEW) );
+/// assert_eq!( maybe_zeroable!([u8; 4]).map(|x| x.zeroed()), Some([0; 4]) );
+///
+/// assert_eq!( maybe_zeroable!(NonZeroU8), None );
+/// assert_eq!( maybe_zeroable!(&'static str), None );
+///
+/// // The generic type parameter isn't known to be Zeroable,
+/// // so this always returns `None`.
+/// fn generic<T>() -> Option<AssertZeroable<T>> {
+///     maybe_zeroable!(T)
+/// }
+///
+/// assert_eq!( generic::<u32>(), None );
+///
+/// ```
+///
+/// Using the macro to choose how to construct a vector:
+///
+/// ```rust
+/// use zeroable::maybe_zeroable;
+///
+/// macro_rules! vec_of_default {
+///     ($type:ty, $len:expr) => {
+///         match maybe_zeroable!($type) {
+///             Some(assert) => (0..$len).map(|_| assert.zeroed()).collect::<Vec<$type>>(),
+///             None => (0..$len).map(|_| <$type>::default()).collect::<Vec<$type>>(),
+///         }
+///     };
+/// }
+///
+/// assert_eq!( vec_of_default!(u8, 3), vec![0, 0, 0] );
+/// assert_eq!( vec_of_default!(String, 2), vec![String::new(), String::new()] );
+///
+/// ```
+#[macro_export]
+macro_rules! maybe_zeroable {
+    ( $type:ty ) => {{
+        #[allow(unused_imports)]
+        use $crate::maybe_zeroable::{ViaNotZeroable as _, ViaZeroable as _};
+
+        (&&$crate::maybe_zeroable::MaybeZeroable::<$type>::NEW).maybe_zeroable()
+    }};
+}
+
+/// Declares statics initialized with zeroes.
+///
+/// Since the initial value of these statics is all zero bytes,
+/// they are usually placed in the `.bss` section,
+/// which doesn't take up space in the binary.
+///
+/// The type of immutable statics is wrapped in a
+/// [`ZeroedStatic`](./statics/struct.ZeroedStatic.html),
+/// which dereferences to the declared type,
+/// because immutable statics are otherwise placed in read-only memory.
+/// `static mut`s keep the declared type.
+///
+/// This requires the "const_zeroed" feature,which requires Rust 1.75.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{zeroed_static, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// pub struct Samples {
+///     pub values: [u64; 1024],
+///     pub len: usize,
+/// }
+///
+/// zeroed_static! {
+///     /// A large lookup table.
+///     pub static TABLE: [u32; 4096];
+///
+///     static SAMPLES: Samples;
+///
+///     static mut SCRATCH: [u8; 4096];
+/// }
+///
+/// assert_eq!( TABLE[1000], 0 );
+/// assert_eq!( SAMPLES.values[..SAMPLES.len], [] );
+///
+/// ```
+#[cfg(feature = "const_zeroed")]
+#[macro_export]
+macro_rules! zeroed_static {
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis static mut $name:ident : $type:ty ;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis static mut $name: $type = $crate::AssertZeroable::<$type>::NEW.zeroed_const();
+
+        $crate::zeroed_static!{ $($rest)* }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident : $type:ty ;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $crate::statics::ZeroedStatic<$type> =
+            $crate::statics::ZeroedStatic::new($crate::AssertZeroable::<$type>::NEW);
+
+        $crate::zeroed_static!{ $($rest)* }
+    };
+}
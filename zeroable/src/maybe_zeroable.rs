@@ -0,0 +1,77 @@
+//! Best-effort detection of whether a type implements `Zeroable`,
+//! used by the [`maybe_zeroable`](../macro.maybe_zeroable.html) macro.
+//!
+//! This uses autoref-based specialization,
+//! which chooses between the impls of two traits with a method of the same name,
+//! preferring the impl that requires the type to be `Zeroable`.
+//!
+//! The `Zeroable` derive also uses this to warn about `#[zero(nonzero)]` attributes
+//! on fields whose type is `Zeroable`.
+
+use crate::{AssertZeroable, Zeroable};
+
+use core::marker::PhantomData;
+
+/// The type that the `maybe_zeroable` macro calls the `maybe_zeroable` method on.
+#[doc(hidden)]
+pub struct MaybeZeroable<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> MaybeZeroable<T> {
+    #[doc(hidden)]
+    pub const NEW: Self = MaybeZeroable(PhantomData);
+}
+
+/// The impl chosen by the `maybe_zeroable` macro when `T` is `Zeroable`.
+#[doc(hidden)]
+pub trait ViaZeroable<T: ?Sized> {
+    fn maybe_zeroable(&self) -> Option<AssertZeroable<T>>;
+}
+
+impl<T> ViaZeroable<T> for &MaybeZeroable<T>
+where
+    T: Zeroable,
+{
+    #[inline(always)]
+    fn maybe_zeroable(&self) -> Option<AssertZeroable<T>> {
+        Some(AssertZeroable::NEW)
+    }
+}
+
+/// The impl chosen by the `maybe_zeroable` macro when `T` is not known to be `Zeroable`.
+#[doc(hidden)]
+pub trait ViaNotZeroable<T: ?Sized> {
+    fn maybe_zeroable(&self) -> Option<AssertZeroable<T>>;
+}
+
+impl<T> ViaNotZeroable<T> for MaybeZeroable<T>
+where
+    T: ?Sized,
+{
+    #[inline(always)]
+    fn maybe_zeroable(&self) -> Option<AssertZeroable<T>> {
+        None
+    }
+}
+
+/// The impl chosen by the `Zeroable` derive when a `#[zero(nonzero)]` field is `Zeroable`,
+/// which warns that the attribute is redundant.
+#[doc(hidden)]
+pub trait RedundantNonzero {
+    #[deprecated(note = "The type of this field implements `Zeroable`,\
+                         so the `#[zero(nonzero)]` attribute isn't necessary.\n\
+                         Use `#[zero(allow(redundant_nonzero))]` to silence this warning.")]
+    #[inline(always)]
+    fn redundant_nonzero(&self) {}
+}
+
+impl<T> RedundantNonzero for &MaybeZeroable<T> where T: Zeroable {}
+
+/// The impl chosen by the `Zeroable` derive when a `#[zero(nonzero)]` field
+/// is not known to be `Zeroable`.
+#[doc(hidden)]
+pub trait NotRedundantNonzero {
+    #[inline(always)]
+    fn redundant_nonzero(&self) {}
+}
+
+impl<T> NotRedundantNonzero for MaybeZeroable<T> where T: ?Sized {}
@@ -0,0 +1,489 @@
+//! Contains the `ZeroPool` object pool,which zeroes values when they're returned to it,
+//! and its thread-safe counterpart `SyncZeroPool`(which requires the "std" feature).
+
+use crate::Zeroable;
+
+use alloc::{
+    alloc::{alloc_zeroed, handle_alloc_error, Layout},
+    boxed::Box,
+    vec::Vec,
+};
+
+use core::{
+    cell::RefCell,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard};
+
+/// Statistics about the values of a pool.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// How many values the pool has allocated.
+    pub allocated: u64,
+    /// How many values were handed out,including the ones that were reused.
+    pub acquired: u64,
+    /// How many times a value couldn't be handed out because the pool was at capacity.
+    pub rejected: u64,
+    /// How many values are currently handed out.
+    pub in_use: usize,
+    /// How many zeroed values are waiting in the pool to be handed out.
+    pub idle: usize,
+}
+
+/// The state shared by `ZeroPool` and `SyncZeroPool`.
+struct PoolState<T> {
+    idle: Vec<Box<T>>,
+    stats: PoolStats,
+}
+
+// The constructors don't have a `T: Zeroable` bound,
+// because trait bounds on `const fn`s require Rust 1.61.
+impl<T> PoolState<T> {
+    const fn new() -> Self {
+        Self {
+            idle: Vec::new(),
+            stats: PoolStats {
+                allocated: 0,
+                acquired: 0,
+                rejected: 0,
+                in_use: 0,
+                idle: 0,
+            },
+        }
+    }
+}
+
+impl<T> PoolState<T>
+where
+    T: Zeroable,
+{
+    fn acquire(&mut self, capacity: usize) -> Option<Box<T>> {
+        let value = match self.idle.pop() {
+            Some(value) => value,
+            None if self.stats.in_use < capacity => {
+                self.stats.allocated += 1;
+                new_zeroed_box()
+            }
+            None => {
+                self.stats.rejected += 1;
+                return None;
+            }
+        };
+        self.stats.acquired += 1;
+        self.stats.in_use += 1;
+        self.stats.idle = self.idle.len();
+        Some(value)
+    }
+
+    /// Returns an already zeroed value to the pool.
+    fn release(&mut self, value: Box<T>) {
+        self.idle.push(value);
+        self.stats.in_use -= 1;
+        self.stats.idle = self.idle.len();
+    }
+
+    fn clear_idle(&mut self) {
+        self.idle = Vec::new();
+        self.stats.idle = 0;
+    }
+}
+
+/// Allocates a zeroed `T` directly on the heap,without a zeroed `T` temporary on the stack.
+fn new_zeroed_box<T>() -> Box<T>
+where
+    T: Zeroable,
+{
+    let layout = Layout::new::<T>();
+    if layout.size() == 0 {
+        return Box::new(T::zeroed());
+    }
+    unsafe {
+        let ptr = alloc_zeroed(layout) as *mut T;
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        // Safety: the memory was allocated with the layout of `T` by the global allocator,
+        // and a zeroed `T` is valid,since `T` is `Zeroable`.
+        Box::from_raw(ptr)
+    }
+}
+
+/// Drops the value in place,zeroes it,and passes it to `release`.
+///
+/// The value is zeroed and released from a guard,
+/// so that it's returned to the pool even if its destructor panics.
+fn zero_and_release<T, F>(value: Box<T>, release: F)
+where
+    T: Zeroable,
+    F: FnOnce(Box<T>),
+{
+    struct ReleaseGuard<T, F>
+    where
+        T: Zeroable,
+        F: FnOnce(Box<T>),
+    {
+        ptr: *mut T,
+        release: Option<F>,
+    }
+
+    impl<T, F> Drop for ReleaseGuard<T, F>
+    where
+        T: Zeroable,
+        F: FnOnce(Box<T>),
+    {
+        fn drop(&mut self) {
+            // Safety: `ptr` came from `Box::into_raw`,
+            // and a zeroed `T` is valid,since `T` is `Zeroable`.
+            let value = unsafe {
+                ptr::write_bytes(self.ptr, 0, 1);
+                Box::from_raw(self.ptr)
+            };
+            if let Some(release) = self.release.take() {
+                release(value);
+            }
+        }
+    }
+
+    let guard = ReleaseGuard {
+        ptr: Box::into_raw(value),
+        release: Some(release),
+    };
+    // Safety: the value is only dropped here,
+    // and the guard overwrites it with zeroes before it's used again.
+    unsafe { ptr::drop_in_place(guard.ptr) };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A pool of heap-allocated `T`s,which are zeroed when they're returned to the pool.
+///
+/// Values are handed out as [`PoolBox`](./struct.PoolBox.html)s,
+/// which drop the value in place,overwrite it with zeroes,
+/// and return it to the pool when dropped
+/// (the value is returned to the pool even if its destructor panics),
+/// so that every value handed out by the pool is zeroed.
+///
+/// New values are allocated already zeroed (with `alloc_zeroed`),
+/// so large values never exist as zeroed temporaries on the stack.
+///
+/// The pool has a capacity,which is the maximum amount of values that can be handed out at once.
+///
+/// For a pool that can be shared between threads,use
+/// [`SyncZeroPool`](./struct.SyncZeroPool.html) (which requires the "std" feature).
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{pool::ZeroPool, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct Packet {
+///     len: usize,
+///     bytes: [u8; 2048],
+/// }
+///
+/// let pool = ZeroPool::<Packet>::with_capacity(2);
+///
+/// {
+///     let mut packet = pool.acquire().unwrap();
+///     packet.bytes[..5].copy_from_slice(b"hello");
+///     packet.len = 5;
+///
+///     let _other = pool.acquire().unwrap();
+///     // Only 2 packets can be handed out at once.
+///     assert!(pool.acquire().is_none());
+/// }
+///
+/// // The packets were zeroed when they were returned to the pool.
+/// let packet = pool.acquire().unwrap();
+/// assert_eq!(packet.len, 0);
+/// assert!(packet.bytes.iter().all(|&b| b == 0));
+///
+/// let stats = pool.stats();
+/// assert_eq!(stats.allocated, 2);
+/// assert_eq!(stats.acquired, 3);
+/// assert_eq!(stats.rejected, 1);
+/// assert_eq!(stats.in_use, 1);
+/// assert_eq!(stats.idle, 1);
+///
+/// ```
+pub struct ZeroPool<T> {
+    state: RefCell<PoolState<T>>,
+    capacity: usize,
+}
+
+impl<T> ZeroPool<T> {
+    /// Constructs a pool that can hand out any amount of values at once.
+    pub const fn new() -> Self {
+        Self::with_capacity(usize::MAX)
+    }
+
+    /// Constructs a pool that can hand out up to `capacity` values at once.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            state: RefCell::new(PoolState::new()),
+            capacity,
+        }
+    }
+}
+
+impl<T> ZeroPool<T>
+where
+    T: Zeroable,
+{
+    /// The maximum amount of values that can be handed out at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Hands out a zeroed value,
+    /// reusing one that was returned to the pool if there is any.
+    ///
+    /// Returns `None` if `capacity` values are already handed out.
+    pub fn acquire(&self) -> Option<PoolBox<'_, T>> {
+        let value = self.state.borrow_mut().acquire(self.capacity)?;
+        Some(PoolBox {
+            value: mem::ManuallyDrop::new(value),
+            pool: self,
+        })
+    }
+
+    /// Gets statistics about the values of the pool.
+    pub fn stats(&self) -> PoolStats {
+        self.state.borrow().stats
+    }
+
+    /// Deallocates the values that are waiting in the pool to be handed out.
+    pub fn clear_idle(&self) {
+        self.state.borrow_mut().clear_idle();
+    }
+}
+
+impl<T> Default for ZeroPool<T>
+where
+    T: Zeroable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ZeroPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZeroPool")
+            .field("capacity", &self.capacity)
+            .field("stats", &self.state.borrow().stats)
+            .finish()
+    }
+}
+
+/// A value handed out by a [`ZeroPool`](./struct.ZeroPool.html),
+/// which is zeroed and returned to the pool when this is dropped.
+pub struct PoolBox<'a, T>
+where
+    T: Zeroable,
+{
+    value: mem::ManuallyDrop<Box<T>>,
+    pool: &'a ZeroPool<T>,
+}
+
+impl<'a, T> Drop for PoolBox<'a, T>
+where
+    T: Zeroable,
+{
+    fn drop(&mut self) {
+        // Safety: `value` isn't used after this.
+        let value = unsafe { mem::ManuallyDrop::take(&mut self.value) };
+        zero_and_release(value, |value| self.pool.state.borrow_mut().release(value));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A thread-safe [`ZeroPool`](./struct.ZeroPool.html),
+/// which uses a `std::sync::Mutex` to synchronize access to the pool.
+///
+/// Values are handed out as [`SyncPoolBox`](./struct.SyncPoolBox.html)s,
+/// which zero the value in place and return it to the pool when dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::pool::SyncZeroPool;
+///
+/// static BUFFERS: SyncZeroPool<[u64; 512]> = SyncZeroPool::with_capacity(8);
+///
+/// let threads = (0..4)
+///     .map(|i| {
+///         std::thread::spawn(move || {
+///             let mut buffer = BUFFERS.acquire().unwrap();
+///             assert!(buffer.iter().all(|&x| x == 0));
+///             buffer[0] = i;
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+///
+/// let stats = BUFFERS.stats();
+/// assert_eq!(stats.acquired, 4);
+/// assert_eq!(stats.in_use, 0);
+/// assert!(BUFFERS.acquire().unwrap().iter().all(|&x| x == 0));
+///
+/// ```
+#[cfg(feature = "std")]
+pub struct SyncZeroPool<T> {
+    state: Mutex<PoolState<T>>,
+    capacity: usize,
+}
+
+#[cfg(feature = "std")]
+impl<T> SyncZeroPool<T> {
+    /// Constructs a pool that can hand out any amount of values at once.
+    pub const fn new() -> Self {
+        Self::with_capacity(usize::MAX)
+    }
+
+    /// Constructs a pool that can hand out up to `capacity` values at once.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(PoolState::new()),
+            capacity,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> SyncZeroPool<T>
+where
+    T: Zeroable,
+{
+    /// The maximum amount of values that can be handed out at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Hands out a zeroed value,
+    /// reusing one that was returned to the pool if there is any.
+    ///
+    /// Returns `None` if `capacity` values are already handed out.
+    pub fn acquire(&self) -> Option<SyncPoolBox<'_, T>> {
+        let value = self.lock().acquire(self.capacity)?;
+        Some(SyncPoolBox {
+            value: mem::ManuallyDrop::new(value),
+            pool: self,
+        })
+    }
+
+    /// Gets statistics about the values of the pool.
+    pub fn stats(&self) -> PoolStats {
+        self.lock().stats
+    }
+
+    /// Deallocates the values that are waiting in the pool to be handed out.
+    pub fn clear_idle(&self) {
+        self.lock().clear_idle();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState<T>> {
+        // The state is never left inconsistent by a panic,
+        // since nothing that can panic runs while the lock is held
+        // (values are dropped before the lock is taken to return them to the pool).
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Default for SyncZeroPool<T>
+where
+    T: Zeroable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> fmt::Debug for SyncZeroPool<T>
+where
+    T: Zeroable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncZeroPool")
+            .field("capacity", &self.capacity)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+/// A value handed out by a [`SyncZeroPool`](./struct.SyncZeroPool.html),
+/// which is zeroed and returned to the pool when this is dropped.
+#[cfg(feature = "std")]
+pub struct SyncPoolBox<'a, T>
+where
+    T: Zeroable,
+{
+    value: mem::ManuallyDrop<Box<T>>,
+    pool: &'a SyncZeroPool<T>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, T> Drop for SyncPoolBox<'a, T>
+where
+    T: Zeroable,
+{
+    fn drop(&mut self) {
+        // Safety: `value` isn't used after this.
+        let value = unsafe { mem::ManuallyDrop::take(&mut self.value) };
+        zero_and_release(value, |value| self.pool.lock().release(value));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! pool_box_impls {
+    ($pool_box:ident) => {
+        impl<'a, T> Deref for $pool_box<'a, T>
+        where
+            T: Zeroable,
+        {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.value
+            }
+        }
+
+        impl<'a, T> DerefMut for $pool_box<'a, T>
+        where
+            T: Zeroable,
+        {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.value
+            }
+        }
+
+        impl<'a, T> fmt::Debug for $pool_box<'a, T>
+        where
+            T: Zeroable + fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&**self, f)
+            }
+        }
+    };
+}
+
+pool_box_impls! { PoolBox }
+
+#[cfg(feature = "std")]
+pool_box_impls! { SyncPoolBox }
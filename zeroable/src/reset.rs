@@ -0,0 +1,102 @@
+//! Contains the `Reset` trait,and the `zero_in_place` function.
+
+use crate::Zeroable;
+
+use core::{mem, ptr};
+
+/// For types that can reset some of their fields to zero,
+/// while keeping the value of other fields.
+///
+/// # Derive
+///
+/// This trait can be derived for structs and enums,
+/// where every field that doesn't have a `#[zero(keep)]` attribute is zeroed
+/// (using [`zero_in_place`](./fn.zero_in_place.html)).
+///
+/// For enums,only the fields of the current variant are zeroed,
+/// the variant itself is not changed.
+///
+/// The fields that are zeroed are required to implement `Zeroable`.
+///
+/// The derive uses the same bounds as the `Zeroable` derive,
+/// every type parameter is required to implement `Zeroable`,
+/// unless it's listed in a `#[zero(not_zeroable(...))]` attribute.
+/// `#[zero(bound="...")]` attributes can be used to add more bounds to the impl.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{Reset, Zeroable};
+///
+/// #[derive(Debug, PartialEq, Reset)]
+/// #[zero(not_zeroable(T))]
+/// struct FrameScratch<T> {
+///     frame: u64,
+///     hits: [u32; 4],
+///     #[zero(keep)]
+///     allocation: Vec<T>,
+/// }
+///
+/// let mut scratch = FrameScratch {
+///     frame: 10,
+///     hits: [1, 2, 3, 4],
+///     allocation: vec!["hello", "world"],
+/// };
+///
+/// scratch.reset();
+///
+/// assert_eq!(
+///     scratch,
+///     FrameScratch {
+///         frame: 0,
+///         hits: [0; 4],
+///         allocation: vec!["hello", "world"],
+///     },
+/// );
+///
+/// ```
+pub trait Reset {
+    /// Zeroes every field,except for the ones that the type keeps.
+    fn reset(&mut self);
+}
+
+/// Overwrites `value` with a zeroed `T`.
+///
+/// If `T` doesn't need to be dropped,this writes zero bytes directly into `value`,
+/// otherwise it assigns `T::zeroed()` to `value`,dropping the previous value.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{zero_in_place, Zeroable};
+///
+/// let mut array = [3_u64; 64];
+/// zero_in_place(&mut array);
+/// assert_eq!( array[..], [0; 64][..] );
+///
+/// #[derive(Zeroable)]
+/// struct Noisy(u32);
+///
+/// impl Drop for Noisy {
+///     fn drop(&mut self) {
+///         println!("dropping Noisy({})", self.0);
+///     }
+/// }
+///
+/// let mut noisy = Noisy(5);
+/// // Prints "dropping Noisy(5)"
+/// zero_in_place(&mut noisy);
+/// assert_eq!( noisy.0, 0 );
+///
+/// ```
+#[inline]
+pub fn zero_in_place<T>(value: &mut T)
+where
+    T: Zeroable,
+{
+    if mem::needs_drop::<T>() {
+        *value = T::zeroed();
+    } else {
+        unsafe { ptr::write_bytes(value as *mut T, 0, 1) }
+    }
+}
@@ -0,0 +1,60 @@
+//! Contains the `ZeroedStatic` type,used by the `zeroed_static` macro.
+
+use crate::AssertZeroable;
+
+use core::{
+    cell::UnsafeCell,
+    fmt::{self, Debug},
+    ops::Deref,
+};
+
+/// A zero-initialized value meant to be stored in a `static`.
+///
+/// Immutable statics are usually placed in read-only memory,
+/// which takes up space in the binary even if the value is all zero bytes.
+/// Because this type wraps the value in an `UnsafeCell`,
+/// it's placed in writable memory instead,
+/// where all zero statics usually go in the `.bss` section,
+/// which doesn't take up space in the binary.
+///
+/// The wrapped value is never mutated through the `UnsafeCell`,
+/// it's only accessible by shared reference through the `Deref` impl.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{statics::ZeroedStatic, AssertZeroable};
+///
+/// static TABLE: ZeroedStatic<[u16; 4096]> = ZeroedStatic::new(AssertZeroable::NEW);
+///
+/// assert_eq!( TABLE[100], 0 );
+/// assert_eq!( TABLE.len(), 4096 );
+///
+/// ```
+#[repr(transparent)]
+pub struct ZeroedStatic<T>(UnsafeCell<T>);
+
+unsafe impl<T: Sync> Sync for ZeroedStatic<T> {}
+
+impl<T> ZeroedStatic<T> {
+    /// Constructs a `ZeroedStatic<T>` wrapping a zeroed `T`.
+    #[inline(always)]
+    pub const fn new(assert: AssertZeroable<T>) -> Self {
+        ZeroedStatic(UnsafeCell::new(assert.zeroed_const()))
+    }
+}
+
+impl<T> Deref for ZeroedStatic<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { &*self.0.get() }
+    }
+}
+
+impl<T: Debug> Debug for ZeroedStatic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
@@ -1,11 +1,21 @@
-use crate::Zeroable;
+use crate::{is_zeroed, is_zeroed_slice, FieldLayout, IsZero, Reset, Zeroable, ZeroableLayout};
 
 use core::{
     cmp::PartialEq,
     fmt::Debug,
+    marker::PhantomData,
     num::{NonZeroU64, NonZeroU8},
 };
 
+extern crate std;
+
+use std::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 #[cfg(feature = "nightly_testing")]
 mod nightly;
 
@@ -192,3 +202,1221 @@ fn stable_struct_test() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////
+
+mod remote_ffi {
+    #[repr(C)]
+    pub struct Timeval {
+        pub tv_sec: i64,
+        pub tv_usec: i32,
+    }
+
+    #[repr(C)]
+    pub struct Pair(pub u16, pub u32);
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub union IntOrFloat {
+        pub int: u32,
+        pub float: f32,
+    }
+}
+
+#[derive(Zeroable)]
+#[zero(remote = "remote_ffi::Timeval")]
+#[repr(C)]
+struct TimevalMirror {
+    tv_sec: i64,
+    tv_usec: i32,
+}
+
+#[derive(Zeroable)]
+#[zero(remote = "remote_ffi::Pair", remote_newtype = "ZeroablePair")]
+#[repr(C)]
+struct PairMirror(u16, u32);
+
+crate::remote! {
+    #[zero(remote = "remote_ffi::IntOrFloat")]
+    #[repr(C)]
+    union IntOrFloatMirror {
+        int: u32,
+        float: f32,
+    }
+}
+
+#[test]
+fn remote_test() {
+    let timeval = remote_ffi::Timeval::zeroed();
+    assert_eq!((timeval.tv_sec, timeval.tv_usec), (0, 0));
+
+    let ZeroablePair(pair) = ZeroablePair::zeroed();
+    assert_eq!((pair.0, pair.1), (0, 0));
+
+    unsafe {
+        assert_eq!(remote_ffi::IntOrFloat::zeroed().int, 0);
+        assert_eq!(remote_ffi::IntOrFloat::zeroed().float, 0.0);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+assert_zeroable!(u8, Struct2Fields<u8>, [EnumC<u8, NonZeroU8>; 2]);
+
+assert_zeroable!(impl['a, T] Struct3Fields<T, &'a str> where T: 'a + PartialEq + Debug + Zeroable);
+
+assert_not_zeroable!(NonZeroU8, Struct2Fields<NonZeroU8>, EnumC<NonZeroU8, u8>);
+
+assert_not_zeroable!(impl[T: Debug] Struct2Fields<T>);
+
+const _: () = {
+    assert_zeroable!(Struct2Fields<u16>);
+    assert_not_zeroable!(Struct2Fields<&'static str>);
+};
+
+#[test]
+fn assert_zeroable_in_fn() {
+    assert_zeroable!(u8, Struct2Fields<u8>,);
+    assert_not_zeroable!(&'static str,);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn maybe_zeroable_bounded<T: Zeroable>() -> Option<crate::AssertZeroable<T>> {
+    maybe_zeroable!(T)
+}
+
+fn maybe_zeroable_unbounded<T>() -> Option<crate::AssertZeroable<T>> {
+    maybe_zeroable!(T)
+}
+
+#[test]
+fn maybe_zeroable_test() {
+    assert!(maybe_zeroable!(u8).is_some());
+    assert!(maybe_zeroable!(Struct2Fields<u8>).is_some());
+    assert!(maybe_zeroable!(EnumC<u8, NonZeroU8>).is_some());
+    assert!(maybe_zeroable!(*const str).is_some());
+
+    assert!(maybe_zeroable!(NonZeroU8).is_none());
+    assert!(maybe_zeroable!(Struct2Fields<NonZeroU8>).is_none());
+    assert!(maybe_zeroable!(dyn Debug).is_none());
+
+    assert_eq!(
+        maybe_zeroable!(Struct2Fields<u16>).map(|x| x.zeroed()),
+        Some(Struct2Fields { a: 0, b: 0 }),
+    );
+
+    assert!(maybe_zeroable_bounded::<u8>().is_some());
+    assert!(maybe_zeroable_unbounded::<u8>().is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn combined_proofs<A, B>(
+    a: crate::AssertZeroable<A>,
+    b: crate::AssertZeroable<B>,
+) -> crate::AssertZeroable<(core::mem::ManuallyDrop<[A; 2]>, core::num::Wrapping<B>)> {
+    a.array::<2>().manually_drop().pair(b.wrapping())
+}
+
+#[test]
+fn combining_assert_zeroable() {
+    use crate::AssertZeroable;
+    use core::num::Wrapping;
+
+    let (arr, wrapping) =
+        combined_proofs(AssertZeroable::<u8>::NEW, AssertZeroable::<i64>::NEW).zeroed();
+    assert_eq!(*arr, [0, 0]);
+    assert_eq!(wrapping, Wrapping(0));
+
+    let inner = AssertZeroable::<Wrapping<u32>>::NEW.peel_transparent::<u32>();
+    assert_eq!(inner.zeroed(), 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+const ZEROED_STRUCT: Struct2Fields<u32> = crate::AssertZeroable::NEW.zeroed_const();
+
+zeroed_static! {
+    static ZEROED_STATIC: Struct2Fields<[u64; 8]>;
+
+    #[allow(dead_code)]
+    pub(crate) static mut ZEROED_STATIC_MUT: [Option<NonZeroU8>; 4];
+}
+
+#[test]
+fn zeroed_const_test() {
+    assert_eq!(ZEROED_STRUCT, Struct2Fields { a: 0, b: 0 });
+    assert_eq!(*ZEROED_STATIC, Struct2Fields { a: 0, b: [0; 8] });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn zeroed_ref_test() {
+    use crate::{zeroed_ref, zeroed_slice_ref};
+
+    #[derive(Debug, PartialEq, Copy, Clone, Zeroable)]
+    #[repr(C, align(64))]
+    struct Aligned {
+        a: u8,
+        b: [u64; 4],
+    }
+
+    let aligned = zeroed_ref::<Aligned>();
+    assert_eq!(*aligned, Aligned { a: 0, b: [0; 4] });
+    assert_eq!(aligned as *const Aligned as usize % 64, 0);
+
+    assert_eq!(zeroed_ref::<[u8; 4096]>()[..], [0; 4096][..]);
+
+    assert_eq!(zeroed_slice_ref::<Aligned>(64).len(), 64);
+    assert!(zeroed_slice_ref::<u64>(512).iter().all(|&x| x == 0));
+    assert_eq!(zeroed_slice_ref::<u8>(0), &[]);
+}
+
+#[test]
+#[should_panic]
+fn zeroed_slice_ref_too_long() {
+    crate::zeroed_slice_ref::<u64>(513);
+}
+
+#[test]
+#[should_panic]
+fn zeroed_slice_ref_overflow() {
+    crate::zeroed_slice_ref::<u64>(usize::MAX);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zero(with_rest)]
+struct WithRest {
+    #[zero(nonzero)]
+    id: NonZeroU64,
+    counter: u32,
+    #[zero(nonzero)]
+    name: &'static str,
+    values: [u16; 4],
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zero(nonzero_fields, with_rest)]
+#[zero(not_zeroable(T))]
+struct WithRestTuple<T>(T, #[zero(zeroable)] usize);
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zero(with_rest)]
+struct WithRestAllZeroable<T> {
+    a: T,
+    b: u8,
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zero(with_rest)]
+struct WithRestGenericParam<T> {
+    #[zero(nonzero)]
+    data: Box<[T]>,
+    len: usize,
+}
+
+#[test]
+fn with_rest_test() {
+    let id = NonZeroU64::new(3).unwrap();
+    assert_eq!(
+        WithRest::zeroed_with(id, "hello"),
+        WithRest {
+            id,
+            counter: 0,
+            name: "hello",
+            values: [0; 4],
+        }
+    );
+
+    assert_eq!(
+        WithRestTuple::zeroed_with("world"),
+        WithRestTuple("world", 0)
+    );
+
+    assert_eq!(
+        WithRestAllZeroable::<u64>::zeroed_with(),
+        WithRestAllZeroable::zeroed()
+    );
+
+    // `T` is only used by a field that's passed as a parameter,
+    // so it doesn't need to be `Zeroable`.
+    let data: Box<[String]> = vec!["foo".to_string()].into();
+    assert_eq!(
+        WithRestGenericParam::zeroed_with(data.clone()),
+        WithRestGenericParam { data, len: 0 }
+    );
+}
+
+assert_not_zeroable!(WithRest, WithRestTuple<u8>, WithRestGenericParam<u8>);
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable)]
+struct DropsPrevious(u32);
+
+static DROPPED: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+impl Drop for DropsPrevious {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(self.0, core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[derive(Debug, PartialEq, Reset)]
+#[zero(not_zeroable(T))]
+struct ResetStruct<T: 'static> {
+    counter: u64,
+    #[zero(keep)]
+    kept: Vec<T>,
+    optional: Option<&'static T>,
+    drops: DropsPrevious,
+}
+
+#[derive(Debug, PartialEq, Reset)]
+struct ResetTuple(u32, #[zero(keep)] String, [u8; 3]);
+
+#[derive(Debug, PartialEq, Reset)]
+#[zero(not_zeroable(T))]
+enum ResetEnum<T> {
+    Empty,
+    Tuple(u8, #[zero(keep)] T),
+    Struct {
+        #[zero(keep)]
+        name: &'static str,
+        value: i64,
+    },
+}
+
+#[test]
+fn reset_test() {
+    let mut struct_ = ResetStruct {
+        counter: 5,
+        kept: vec!["hello"],
+        optional: Some(&"world"),
+        drops: DropsPrevious(7),
+    };
+    struct_.reset();
+    assert_eq!(DROPPED.load(core::sync::atomic::Ordering::SeqCst), 7);
+    assert_eq!(
+        struct_,
+        ResetStruct {
+            counter: 0,
+            kept: vec!["hello"],
+            optional: None,
+            drops: DropsPrevious(0),
+        }
+    );
+
+    let mut tuple = ResetTuple(3, "foo".to_string(), [1, 2, 3]);
+    tuple.reset();
+    assert_eq!(tuple, ResetTuple(0, "foo".to_string(), [0; 3]));
+
+    let mut enum_ = ResetEnum::<String>::Empty;
+    enum_.reset();
+    assert_eq!(enum_, ResetEnum::Empty);
+
+    let mut enum_ = ResetEnum::Tuple(3, "bar".to_string());
+    enum_.reset();
+    assert_eq!(enum_, ResetEnum::Tuple(0, "bar".to_string()));
+
+    let mut enum_ = ResetEnum::<String>::Struct {
+        name: "baz",
+        value: -1,
+    };
+    enum_.reset();
+    assert_eq!(
+        enum_,
+        ResetEnum::Struct {
+            name: "baz",
+            value: 0
+        }
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Zeroable, IsZero)]
+#[repr(C)]
+struct Padded<T> {
+    tag: u8,
+    value: T,
+}
+
+#[derive(Debug, Zeroable, IsZero)]
+#[repr(u8)]
+#[zero(not_zeroable(T))]
+enum IsZeroEnum<T> {
+    First(T) = 1,
+    Zero { value: u32, flag: bool } = 0,
+    Last = 2,
+}
+
+#[derive(IsZero)]
+#[repr(C)]
+enum SingleVariant {
+    Only(u16),
+}
+
+#[test]
+fn is_zeroed_test() {
+    assert!(is_zeroed(&0_u64));
+    assert!(is_zeroed(&[0_u8; 3]));
+    assert!(!is_zeroed(&[0, 0, 1_u8]));
+    assert!(!is_zeroed(&-0.0_f64));
+
+    for len in 0..40 {
+        let mut bytes = vec![0_u8; len];
+        assert!(is_zeroed_slice(&bytes));
+        // Checks every position,so that the unaligned prefix and suffix are covered.
+        for i in 0..len {
+            bytes[i] = 1;
+            assert!(!is_zeroed_slice(&bytes), "len: {} i: {}", len, i);
+            assert!(!is_zeroed_slice(&bytes[i..]), "len: {} i: {}", len, i);
+            assert!(is_zeroed_slice(&bytes[i + 1..]), "len: {} i: {}", len, i);
+            bytes[i] = 0;
+        }
+    }
+
+    assert!(is_zeroed_slice::<u32>(&[]));
+    assert!(!is_zeroed_slice(&[0_u32, 0, 0, 7]));
+}
+
+#[test]
+fn is_zero_test() {
+    assert!(0.0_f32.is_zero());
+    assert!(!(-0.0_f32).is_zero());
+    assert!('\0'.is_zero());
+    assert!((0_u8, false, None::<&str>).is_zero());
+    assert!(!(0_u8, true).is_zero());
+    assert!(core::ptr::null::<u8>().is_zero());
+    assert!(None::<NonZeroU64>.is_zero());
+    assert!(!Some(NonZeroU8::new(1).unwrap()).is_zero());
+    assert!([[0_i32; 2]; 3].is_zero());
+
+    assert!(Padded::<u64>::zeroed().is_zero());
+    assert!(!Padded {
+        tag: 1,
+        value: 0_u64
+    }
+    .is_zero());
+    assert!(!Padded {
+        tag: 0,
+        value: 1_u64
+    }
+    .is_zero());
+    assert!(Padded {
+        tag: 0,
+        value: Padded {
+            tag: 0,
+            value: 0.0_f32
+        }
+    }
+    .is_zero());
+
+    assert!(IsZeroEnum::<String>::zeroed().is_zero());
+    assert!(!IsZeroEnum::<String>::Zero {
+        value: 0,
+        flag: true
+    }
+    .is_zero());
+    assert!(!IsZeroEnum::First(String::new()).is_zero());
+    assert!(!IsZeroEnum::<String>::Last.is_zero());
+
+    assert!(SingleVariant::Only(0).is_zero());
+    assert!(!SingleVariant::Only(3).is_zero());
+}
+
+#[derive(Debug, Zeroable)]
+#[repr(u8)]
+#[zero(not_zeroable(T), variant_info)]
+enum VariantInfoEnum<T> {
+    First(T) = 1,
+    #[allow(dead_code)]
+    Zero {
+        value: u32,
+        flag: bool,
+    } = 0,
+    Last = 2,
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(variant_info)]
+enum VariantInfoSingle {
+    Only(#[allow(dead_code)] u16),
+}
+
+#[test]
+fn variant_info_test() {
+    assert_eq!(VariantInfoEnum::<String>::ZERO_VARIANT_NAME, "Zero");
+    assert!(VariantInfoEnum::<String>::zeroed().is_zero_variant());
+    assert!(VariantInfoEnum::<String>::Zero {
+        value: 3,
+        flag: true
+    }
+    .is_zero_variant());
+    assert!(!VariantInfoEnum::First(String::new()).is_zero_variant());
+    assert!(!VariantInfoEnum::<String>::Last.is_zero_variant());
+
+    assert_eq!(VariantInfoSingle::ZERO_VARIANT_NAME, "Only");
+    assert!(VariantInfoSingle::Only(3).is_zero_variant());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(layout_info)]
+struct LayoutStruct<T> {
+    tag: u8,
+    value: T,
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(layout_info)]
+struct LayoutTuple(u16, [u8; 3], u64);
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(with_rest, layout_info)]
+struct LayoutWithRest {
+    count: u32,
+    #[zero(nonzero)]
+    id: NonZeroU64,
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(nonzero_fields, layout_info)]
+union LayoutUnion {
+    #[zero(zeroable)]
+    int: u64,
+    float: f32,
+}
+
+fn field(
+    name: &'static str,
+    type_name: &'static str,
+    offset: usize,
+    size: usize,
+    zeroable: bool,
+) -> FieldLayout {
+    FieldLayout {
+        name,
+        type_name,
+        offset,
+        size,
+        zeroable,
+    }
+}
+
+#[test]
+fn layout_info_test() {
+    assert_eq!(
+        LayoutStruct::<u32>::ZEROABLE_LAYOUT,
+        &[
+            field("tag", "u8", 0, 1, true),
+            field("value", "T", 4, 4, true)
+        ][..]
+    );
+    assert_eq!(
+        LayoutStruct::<u16>::ZEROABLE_LAYOUT,
+        &[
+            field("tag", "u8", 0, 1, true),
+            field("value", "T", 2, 2, true)
+        ][..]
+    );
+    assert_eq!(
+        LayoutTuple::ZEROABLE_LAYOUT,
+        &[
+            field("0", "u16", 0, 2, true),
+            field("1", "[u8; 3]", 2, 3, true),
+            field("2", "u64", 8, 8, true),
+        ][..]
+    );
+    assert_eq!(
+        LayoutWithRest::ZEROABLE_LAYOUT,
+        &[
+            field("count", "u32", 0, 4, true),
+            field("id", "NonZeroU64", 8, 8, false),
+        ][..]
+    );
+    assert_eq!(
+        LayoutUnion::ZEROABLE_LAYOUT,
+        &[
+            field("int", "u64", 0, 8, true),
+            field("float", "f32", 0, 4, false),
+        ][..]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable, IsZero)]
+#[repr(u8)]
+#[zero(bound = "")]
+enum FieldBoundEnum<T, U> {
+    Inline(#[zero(bound(T: Zeroable + IsZero))] T),
+    Boxed(#[zero(bound = "U: Zeroable + IsZero")] Box<U>),
+}
+
+#[derive(Zeroable)]
+#[zero(bound = "")]
+union FieldBoundUnion<T: Copy> {
+    #[zero(bound = "T: Zeroable")]
+    value: T,
+    #[zero(nonzero, bound(T: Debug))]
+    ptr: &'static u32,
+}
+
+#[derive(Copy, Clone, Zeroable)]
+struct NotDebug;
+
+#[test]
+fn field_bound_test() {
+    assert_eq!(
+        FieldBoundEnum::<u32, String>::zeroed(),
+        FieldBoundEnum::Inline(0)
+    );
+    assert!(FieldBoundEnum::<u32, String>::zeroed().is_zero());
+    assert!(!FieldBoundEnum::<u32, String>::Boxed(Box::new(String::new())).is_zero());
+
+    unsafe {
+        assert_eq!(FieldBoundUnion::<u64>::zeroed().value, 0);
+
+        // `NotDebug` doesn't implement `Debug`,the bound of the `ptr` field isn't added.
+        assert_eq!(*FieldBoundUnion::<NotDebug> { ptr: &5 }.ptr, 5);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zeroable(bound = "")]
+struct BytemuckAlwaysZeroable<T> {
+    a: PhantomData<T>,
+    b: u32,
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(u8)]
+#[zeroable(bound = "T: Copy")]
+enum BytemuckEnum<T, U> {
+    Zero(Option<T>),
+    Other(U),
+}
+
+#[test]
+fn bytemuck_bound_test() {
+    assert_eq!(
+        BytemuckAlwaysZeroable::<NonZeroU8>::zeroed(),
+        BytemuckAlwaysZeroable {
+            a: PhantomData,
+            b: 0
+        }
+    );
+    assert_eq!(
+        BytemuckEnum::<&'static u8, String>::zeroed(),
+        BytemuckEnum::Zero(None)
+    );
+    assert_ne!(
+        BytemuckEnum::<&'static u8, String>::zeroed(),
+        BytemuckEnum::Other(String::new())
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(C, align(64))]
+struct OverAligned {
+    bytes: [u8; 3],
+}
+
+static ARENA_DROPPED: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+#[derive(Zeroable)]
+struct ArenaDropped(u32);
+
+impl Drop for ArenaDropped {
+    fn drop(&mut self) {
+        ARENA_DROPPED.fetch_add(self.0, core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn arena_test() {
+    use crate::arena::ZeroArena;
+
+    let mut arena = ZeroArena::with_page_size(256);
+    assert_eq!(arena.page_count(), 0);
+
+    for _ in 0..3 {
+        let byte = arena.alloc_zeroed::<u8>();
+        assert_eq!(*byte, 0);
+        *byte = 1;
+
+        let aligned = arena.alloc_zeroed::<OverAligned>();
+        assert_eq!(*aligned, OverAligned { bytes: [0; 3] });
+        assert_eq!(aligned as *mut OverAligned as usize % 64, 0);
+        aligned.bytes = [1; 3];
+
+        let words = arena.alloc_slice_zeroed::<u64>(20);
+        assert_eq!(words, &[0; 20][..]);
+        assert_eq!(words.as_ptr() as usize % core::mem::align_of::<u64>(), 0);
+        words.iter_mut().for_each(|x| *x = !0);
+
+        // Larger than a page
+        let large = arena.alloc_slice_zeroed::<u32>(1000);
+        assert!(large.iter().all(|&x| x == 0));
+        large.iter_mut().for_each(|x| *x = 7);
+
+        let units = arena.alloc_slice_zeroed::<()>(usize::MAX);
+        assert_eq!(units.len(), usize::MAX);
+
+        arena.reset();
+    }
+
+    // The pages are reused after a reset.
+    let page_count = arena.page_count();
+    assert!(page_count >= 2);
+    assert!(arena.allocated_bytes() >= 256 + 4000);
+    for _ in 0..100 {
+        assert_eq!(*arena.alloc_zeroed::<[u64; 2]>(), [0; 2]);
+    }
+    assert_eq!(arena.page_count(), page_count);
+
+    arena.alloc_zeroed::<ArenaDropped>().0 = 100;
+    arena.alloc_zeroed_with_drop::<ArenaDropped>().0 = 1;
+    arena.alloc_zeroed_with_drop::<ArenaDropped>().0 = 2;
+    arena.reset();
+    assert_eq!(ARENA_DROPPED.load(core::sync::atomic::Ordering::SeqCst), 3);
+
+    arena.alloc_zeroed_with_drop::<ArenaDropped>().0 = 4;
+    drop(arena);
+    assert_eq!(ARENA_DROPPED.load(core::sync::atomic::Ordering::SeqCst), 7);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+static POOL_DROPPED: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+#[derive(Debug, Zeroable)]
+struct PooledBuffer {
+    len: usize,
+    bytes: [u8; 64],
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        POOL_DROPPED.fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn pool_test() {
+    use crate::pool::{PoolStats, ZeroPool};
+
+    let pool = ZeroPool::<PooledBuffer>::with_capacity(2);
+    assert_eq!(pool.capacity(), 2);
+
+    {
+        let mut a = pool.acquire().unwrap();
+        a.len = 3;
+        a.bytes[..3].copy_from_slice(b"abc");
+
+        let b = pool.acquire().unwrap();
+        assert_eq!(b.len, 0);
+        assert!(pool.acquire().is_none());
+    }
+    // The values with a `Drop` impl are dropped when they're zeroed.
+    assert_eq!(POOL_DROPPED.load(core::sync::atomic::Ordering::SeqCst), 2);
+
+    for _ in 0..2 {
+        let a = pool.acquire().unwrap();
+        assert_eq!(a.len, 0);
+        assert_eq!(a.bytes[..], [0; 64][..]);
+    }
+
+    assert_eq!(
+        pool.stats(),
+        PoolStats {
+            allocated: 2,
+            acquired: 4,
+            rejected: 1,
+            in_use: 0,
+            idle: 2,
+        }
+    );
+
+    pool.clear_idle();
+    assert_eq!(pool.stats().idle, 0);
+
+    let unit_pool = ZeroPool::<()>::new();
+    let units = (0..100)
+        .map(|_| unit_pool.acquire().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(unit_pool.stats().in_use, 100);
+    drop(units);
+    assert_eq!(unit_pool.stats().idle, 100);
+}
+
+#[test]
+fn sync_pool_test() {
+    use crate::pool::SyncZeroPool;
+
+    let pool = SyncZeroPool::<[u32; 256]>::with_capacity(4);
+
+    std::thread::scope(|scope| {
+        for i in 0..4 {
+            let pool = &pool;
+            scope.spawn(move || {
+                for _ in 0..100 {
+                    let mut buffer = pool.acquire().unwrap();
+                    assert!(buffer.iter().all(|&x| x == 0));
+                    buffer.iter_mut().for_each(|x| *x = i + 1);
+                }
+            });
+        }
+    });
+
+    let stats = pool.stats();
+    assert_eq!(stats.acquired, 400);
+    assert_eq!(stats.rejected, 0);
+    assert_eq!(stats.in_use, 0);
+    assert!(stats.allocated <= 4);
+
+    let buffers = (0..4).map(|_| pool.acquire().unwrap()).collect::<Vec<_>>();
+    assert!(buffers.iter().all(|b| b.iter().all(|&x| x == 0)));
+    assert!(pool.acquire().is_none());
+}
+
+#[derive(Zeroable)]
+struct PanicsOnDrop {
+    panics: bool,
+    value: u64,
+}
+
+impl Drop for PanicsOnDrop {
+    fn drop(&mut self) {
+        if self.panics {
+            panic!("PanicsOnDrop was dropped");
+        }
+    }
+}
+
+#[test]
+fn pool_panicking_drop_test() {
+    use crate::pool::{SyncZeroPool, ZeroPool};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let pool = ZeroPool::<PanicsOnDrop>::with_capacity(1);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let mut value = pool.acquire().unwrap();
+        value.panics = true;
+        value.value = 100;
+    }));
+    assert!(res.is_err());
+    assert_eq!(pool.stats().in_use, 0);
+    assert_eq!(pool.stats().idle, 1);
+    {
+        let value = pool.acquire().unwrap();
+        assert!(!value.panics);
+        assert_eq!(value.value, 0);
+    }
+
+    let pool = SyncZeroPool::<PanicsOnDrop>::with_capacity(1);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let mut value = pool.acquire().unwrap();
+        value.panics = true;
+        value.value = 100;
+    }));
+    assert!(res.is_err());
+    assert_eq!(pool.stats().in_use, 0);
+    assert_eq!(pool.stats().idle, 1);
+    {
+        let value = pool.acquire().unwrap();
+        assert!(!value.panics);
+        assert_eq!(value.value, 0);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn zeroed_vec_test() {
+    use crate::zeroed_vec::ZeroedVec;
+
+    let mut vec = ZeroedVec::<u64>::new();
+    assert_eq!(vec.capacity(), 0);
+    assert_eq!(vec.pop(), None);
+
+    vec.push(3);
+    vec.push(5);
+    assert_eq!(vec, [3, 5]);
+
+    // Growing across several reallocations,with garbage written to the elements
+    // that are truncated away,to check that the spare capacity is rezeroed.
+    for len in (10..1000).step_by(97) {
+        vec.grow_to(len);
+        assert_eq!(vec.len(), len);
+        assert!(vec[2..].iter().all(|&x| x == 0));
+        vec.iter_mut().skip(2).for_each(|x| *x = !0);
+        vec.truncate_and_zero(2);
+    }
+    assert!(vec.capacity() >= 900);
+
+    *vec.get_or_grow_mut(20) = 8;
+    assert_eq!(vec.len(), 21);
+    assert_eq!(vec[20], 8);
+    *vec.get_or_grow_mut(0) += 1;
+    assert_eq!(vec[..3], [4, 5, 0]);
+
+    assert_eq!(vec.pop(), Some(8));
+    vec.grow_to(21);
+    assert_eq!(vec[20], 0);
+
+    vec.grow_to(5);
+    assert_eq!(vec.len(), 21);
+
+    let cloned = vec.clone();
+    assert_eq!(cloned, vec.as_slice());
+
+    let std_vec = vec.into_vec();
+    assert_eq!(std_vec.len(), 21);
+    assert_eq!(std_vec[..2], [4, 5]);
+
+    let collected = (0..4).collect::<ZeroedVec<u8>>();
+    assert_eq!(collected, [0, 1, 2, 3]);
+
+    let mut units = ZeroedVec::<()>::with_capacity(10);
+    units.grow_to(100);
+    assert_eq!(units.len(), 100);
+    assert_eq!(units.into_vec().len(), 100);
+}
+
+#[test]
+fn zeroed_vec_panicking_drop_test() {
+    use crate::zeroed_vec::ZeroedVec;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut vec = ZeroedVec::<PanicsOnDrop>::new();
+    vec.grow_to(3);
+    vec.iter_mut().for_each(|x| x.value = 100);
+    vec[1].panics = true;
+
+    let res = catch_unwind(AssertUnwindSafe(|| vec.clear()));
+    assert!(res.is_err());
+    assert_eq!(vec.len(), 0);
+
+    // The elements were zeroed even though one of their destructors panicked.
+    vec.grow_to(3);
+    assert!(vec.iter().all(|x| !x.panics && x.value == 0));
+}
+
+#[test]
+fn zeroed_vec_drop_test() {
+    use crate::zeroed_vec::ZeroedVec;
+
+    #[derive(Zeroable)]
+    struct Counted(u32);
+
+    static COUNTED_DROPPED: core::sync::atomic::AtomicU32 = core::sync::atomic::AtomicU32::new(0);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            COUNTED_DROPPED.fetch_add(self.0, core::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    let mut vec = ZeroedVec::<Counted>::new();
+    vec.get_or_grow_mut(3).0 = 1;
+    vec.get_or_grow_mut(5).0 = 10;
+    vec.push(Counted(100));
+
+    vec.truncate_and_zero(4);
+    assert_eq!(
+        COUNTED_DROPPED.load(core::sync::atomic::Ordering::SeqCst),
+        110
+    );
+
+    drop(vec);
+    assert_eq!(
+        COUNTED_DROPPED.load(core::sync::atomic::Ordering::SeqCst),
+        111
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(C)]
+struct FfiOut<T> {
+    size: u32,
+    value: T,
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(u8)]
+enum FfiEnum {
+    Zero,
+    One,
+}
+
+unsafe extern "C" fn ffi_fill(out: *mut FfiOut<u64>, count: usize) -> usize {
+    for i in 0..count {
+        let elem = &mut *out.add(i);
+        elem.size += 8;
+        elem.value += i as u64;
+    }
+    count
+}
+
+#[test]
+fn ffi_test() {
+    use crate::ffi::{with_zeroed_out, with_zeroed_out_array, ReprC, ZeroedOut};
+
+    fn assert_repr_c<T: ReprC>() {}
+    assert_repr_c::<FfiOut<u8>>();
+    assert_repr_c::<FfiEnum>();
+    assert_repr_c::<[*mut FfiEnum; 3]>();
+
+    let (count, out) = with_zeroed_out(|out| unsafe { ffi_fill(out, 1) });
+    assert_eq!(count, 1);
+    assert_eq!(out, FfiOut { size: 8, value: 0 });
+
+    let ((), out) = with_zeroed_out(|_: *mut FfiEnum| ());
+    assert_eq!(out, FfiEnum::Zero);
+
+    let (_, out) = with_zeroed_out(|out: *mut FfiEnum| unsafe { *out = FfiEnum::One });
+    assert_eq!(out, FfiEnum::One);
+
+    let (count, array) =
+        with_zeroed_out_array::<FfiOut<u64>, _, _, 3>(|out, len| unsafe { ffi_fill(out, len - 1) });
+    assert_eq!(count, 2);
+    assert_eq!(
+        array,
+        [
+            FfiOut { size: 8, value: 0 },
+            FfiOut { size: 8, value: 1 },
+            FfiOut { size: 0, value: 0 },
+        ]
+    );
+
+    let ((), empty) = with_zeroed_out_array::<u8, _, _, 0>(|_, len| assert_eq!(len, 0));
+    assert_eq!(empty, []);
+
+    let mut reused = FfiOut {
+        size: 3,
+        value: 5_u64,
+    };
+    for _ in 0..2 {
+        let out = ZeroedOut::new(&mut reused);
+        assert_eq!(unsafe { &**out }, &FfiOut { size: 0, value: 0 });
+        unsafe { ffi_fill(*out, 1) };
+        assert_eq!(out.as_mut_ptr(), *out);
+        assert_eq!(out.into_mut(), &mut FfiOut { size: 8, value: 0 });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Zeroable)]
+#[repr(transparent)]
+struct StructureType(u32);
+
+impl StructureType {
+    const BUFFER_INFO: Self = StructureType(12);
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(C)]
+#[zero(
+    size_field = "cb_size",
+    init(s_type = "StructureType::BUFFER_INFO", flags = "3 + 4")
+)]
+pub struct SizedInfo {
+    cb_size: u16,
+    s_type: StructureType,
+    flags: u32,
+    next: *const u8,
+    name: [u8; 16],
+}
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[repr(C)]
+#[zero(size_field = "0")]
+struct SizedTuple(u64, u32);
+
+#[derive(Debug, PartialEq, Zeroable)]
+#[zero(init(value = "T::default()"))]
+#[zero(bound = "T: Default")]
+struct InitGeneric<T> {
+    value: T,
+    other: T,
+}
+
+#[test]
+fn new_zeroed_test() {
+    let info = SizedInfo::new_zeroed();
+    assert_eq!(info.cb_size as usize, core::mem::size_of::<SizedInfo>());
+    assert_eq!(info.s_type, StructureType::BUFFER_INFO);
+    assert_eq!(info.flags, 7);
+    assert!(info.next.is_null());
+    assert_eq!(info.name, [0; 16]);
+
+    assert_eq!(SizedTuple::new_zeroed(), SizedTuple(16, 0));
+
+    assert_eq!(
+        InitGeneric::<u32>::new_zeroed(),
+        InitGeneric { value: 0, other: 0 }
+    );
+    assert_eq!(
+        InitGeneric::<Option<NonZeroU8>>::new_zeroed(),
+        InitGeneric {
+            value: None,
+            other: None
+        }
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(c_header)]
+struct CList {
+    next: *mut CList,
+    values: [CValue; 2],
+    label: *const *mut u8,
+    _marker: [u8; 0],
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(c_header)]
+union CValue {
+    int: i32,
+    float: f64,
+}
+
+#[derive(Zeroable)]
+#[repr(C, align(16))]
+#[zero(c_header)]
+struct CAlignedPair(u8, u16);
+
+#[test]
+fn c_header_test() {
+    use crate::c_header::{CDefinition, CField, CHeader, CHeaderType, CKind, CType, CTypeDesc};
+
+    assert_eq!(CValue::C_TYPE, CTypeDesc::Named("CValue"));
+    assert_eq!(
+        CValue::C_DEFINITION,
+        CDefinition {
+            name: "CValue",
+            kind: CKind::Union,
+            size: 8,
+            align: core::mem::align_of::<f64>(),
+            fields: &[
+                CField {
+                    name: "int",
+                    ty: CTypeDesc::Named("int32_t"),
+                    offset: 0,
+                    align: 4,
+                },
+                CField {
+                    name: "float",
+                    ty: CTypeDesc::Named("double"),
+                    offset: 0,
+                    align: core::mem::align_of::<f64>(),
+                },
+            ],
+        }
+    );
+
+    let declarations = CList::C_DEFINITION
+        .fields
+        .iter()
+        .map(|field| field.ty.declaration(field.name).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        declarations,
+        [
+            "CList *next",
+            "CValue values[2]",
+            "uint8_t *const *label",
+            "uint8_t _marker[0]",
+        ]
+    );
+
+    let fields = CAlignedPair::C_DEFINITION.fields;
+    assert_eq!(
+        fields
+            .iter()
+            .map(|x| (x.name, x.offset))
+            .collect::<Vec<_>>(),
+        [("_0", 0), ("_1", 2)]
+    );
+    assert_eq!(CAlignedPair::C_DEFINITION.align, 16);
+
+    let header = CHeader::new("TEST_TYPES_H")
+        .add::<CList>()
+        .add::<CAlignedPair>()
+        .add::<CValue>()
+        .add::<CList>()
+        .to_string();
+
+    assert_eq!(header.matches("struct CList {").count(), 1);
+    assert!(header.contains("#ifndef TEST_TYPES_H\n#define TEST_TYPES_H\n"));
+    assert!(header.ends_with("#endif /* TEST_TYPES_H */\n"));
+    assert!(header.contains(
+        "typedef union CValue CValue;\n\
+         typedef struct CList CList;\n\
+         typedef struct CAlignedPair CAlignedPair;\n"
+    ));
+    assert!(header.contains("#define CLIST_ZERO_INIT {0}\n"));
+    assert!(header.contains("#define CALIGNED_PAIR_ZERO_INIT {0}\n"));
+    assert!(header
+        .contains("struct CAlignedPair {\n    _Alignas(16) uint8_t _0;\n    uint16_t _1;\n};\n"));
+    assert!(header.contains(
+        "_Static_assert(sizeof(CAlignedPair) == 16, \
+         \"The size of `CAlignedPair` doesn't match the Rust type\");\n"
+    ));
+    assert!(header.contains(
+        "_Static_assert(offsetof(CAlignedPair, _1) == 2, \
+         \"The offset of `CAlignedPair._1` doesn't match the Rust type\");\n"
+    ));
+    assert!(header.find("union CValue {") < header.find("struct CList {"));
+
+    let path = std::env::temp_dir().join("zeroable_c_header_test.h");
+    let mut builder = CHeader::new("TEST_TYPES_H");
+    builder.add::<CValue>();
+    builder.write_to_file(&path).unwrap();
+    builder.write_to_file(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), builder.to_string());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Two different types named `CValue` were added")]
+fn c_header_name_collision_test() {
+    use crate::c_header::{CHeader, CHeaderType};
+
+    mod other {
+        #[derive(crate::Zeroable)]
+        #[repr(C)]
+        #[zero(c_header)]
+        pub(super) struct CValue(pub(super) u8);
+    }
+
+    let _ = other::CValue(0).0;
+    assert_eq!(other::CValue::C_DEFINITION.name, "CValue");
+    CHeader::new("COLLISION_H")
+        .add::<CValue>()
+        .add::<other::CValue>();
+}
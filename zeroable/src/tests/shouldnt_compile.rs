@@ -140,3 +140,477 @@ pub struct EnumNonCompiling;
 /// }
 /// ```
 pub struct StructNonCompiling;
+
+///////////////////////////////////////////////////////////////////////////////
+
+///
+/// ```compile_fail
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Foreign {
+///         pub a: u32,
+///         pub b: u64,
+///     }
+/// }
+///
+/// zeroable::remote! {
+///     #[zero(remote = "ffi::Foreign", remote_newtype = "Wrapper")]
+///     #[repr(C)]
+///     struct Mirror {
+///         b: u64,
+///         a: u32,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Foreign {
+///         pub a: u32,
+///         pub b: u64,
+///     }
+/// }
+///
+/// zeroable::remote! {
+///     #[zero(remote = "ffi::Foreign", remote_newtype = "Wrapper")]
+///     #[repr(C)]
+///     struct Mirror {
+///         a: u32,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Foreign {
+///         pub a: u32,
+///         pub b: Box<u64>,
+///     }
+/// }
+///
+/// zeroable::remote! {
+///     #[zero(remote = "ffi::Foreign", remote_newtype = "Wrapper")]
+///     #[repr(C)]
+///     struct Mirror {
+///         a: u32,
+///         b: usize,
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Foreign {
+///         pub a: u32,
+///         pub b: u64,
+///     }
+/// }
+///
+/// zeroable::remote! {
+///     #[zero(remote = "ffi::Foreign", remote_newtype = "Wrapper")]
+///     #[repr(C, packed)]
+///     struct Mirror {
+///         a: u32,
+///         b: u64,
+///     }
+/// }
+/// ```
+///
+/// ```rust
+/// mod ffi {
+///     #[repr(C)]
+///     pub struct Foreign {
+///         pub a: u32,
+///         pub b: u64,
+///     }
+/// }
+///
+/// zeroable::remote! {
+///     #[zero(remote = "ffi::Foreign", remote_newtype = "Wrapper")]
+///     #[repr(C)]
+///     struct Mirror {
+///         a: u32,
+///         b: u64,
+///     }
+/// }
+/// ```
+pub struct RemoteNonCompiling;
+
+///////////////////////////////////////////////////////////////////////////////
+
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// use core::num::NonZeroU32;
+///
+/// #[derive(Zeroable)]
+/// #[zero(with_rest)]
+/// struct Struct {
+///     id: NonZeroU32,
+///     count: u32,
+/// }
+/// ```
+///
+/// ```rust
+/// use zeroable::Zeroable;
+///
+/// use core::num::NonZeroU32;
+///
+/// #[derive(Zeroable)]
+/// #[zero(with_rest)]
+/// struct Struct {
+///     #[zero(nonzero)]
+///     id: NonZeroU32,
+///     count: u32,
+/// }
+/// ```
+pub struct WithRestNonCompiling;
+
+///////////////////////////////////////////////////////////////////////////////
+
+///
+/// ```compile_fail
+/// use zeroable::Reset;
+///
+/// #[derive(Reset)]
+/// struct Struct {
+///     count: u32,
+///     buffer: Vec<u8>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::Reset;
+///
+/// #[derive(Reset)]
+/// union Union {
+///     count: u32,
+///     other: u32,
+/// }
+/// ```
+///
+/// ```rust
+/// use zeroable::Reset;
+///
+/// #[derive(Reset)]
+/// struct Struct {
+///     count: u32,
+///     #[zero(keep)]
+///     buffer: Vec<u8>,
+/// }
+/// ```
+pub struct ResetNonCompiling;
+
+///////////////////////////////////////////////////////////////////////////////
+
+///
+/// ```compile_fail
+/// use zeroable::is_zeroed;
+///
+/// // Tuples can have padding,so they don't implement `NoUninit`.
+/// is_zeroed(&(0_u8, 0_u32));
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::IsZero;
+///
+/// #[derive(IsZero)]
+/// enum Enum {
+///     A,
+///     B(u32),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::IsZero;
+///
+/// #[derive(IsZero)]
+/// union Union {
+///     count: u32,
+///     other: u32,
+/// }
+/// ```
+///
+/// ```rust
+/// use zeroable::{is_zeroed, IsZero};
+///
+/// #[derive(IsZero)]
+/// #[repr(u8)]
+/// enum Enum {
+///     A,
+///     B(u32),
+/// }
+///
+/// is_zeroed(&[0_u8, 0_u8]);
+/// ```
+pub struct IsZeroNonCompiling;
+
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// use core::marker::PhantomData;
+///
+/// #[derive(Zeroable)]
+/// #[zeroable(bound = "T: Copy")]
+/// struct ZeroableWhenTIsCopy<T> {
+///     a: PhantomData<T>,
+/// }
+///
+/// let _ = ZeroableWhenTIsCopy::<String>::zeroed();
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[zeroable(bound = "")]
+/// struct Wrapper<T> {
+///     a: Option<T>,
+/// }
+///
+/// let _ = Wrapper::<String>::zeroed();
+/// ```
+///
+/// ```rust
+/// use zeroable::Zeroable;
+///
+/// use core::marker::PhantomData;
+///
+/// #[derive(Zeroable)]
+/// #[zeroable(bound = "T: Copy")]
+/// struct ZeroableWhenTIsCopy<T> {
+///     a: PhantomData<T>,
+/// }
+///
+/// #[derive(Zeroable)]
+/// #[zeroable(bound = "")]
+/// struct Wrapper<T> {
+///     a: Option<T>,
+/// }
+///
+/// let _ = ZeroableWhenTIsCopy::<u8>::zeroed();
+/// let _ = Wrapper::<&'static str>::zeroed();
+/// ```
+pub struct BytemuckBoundNonCompiling;
+
+/// ```compile_fail
+/// use zeroable::{ffi::with_zeroed_out, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct NotReprC {
+///     a: u32,
+/// }
+///
+/// let _ = with_zeroed_out(|_: *mut NotReprC| ());
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::{ffi::ZeroedOut, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[repr(transparent)]
+/// struct Transparent(u32);
+///
+/// let mut value = Transparent(3);
+/// let _ = ZeroedOut::new(&mut value);
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::{ffi::with_zeroed_out, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct GenericReprC<T> {
+///     a: T,
+/// }
+///
+/// let _ = with_zeroed_out(|_: *mut GenericReprC<Option<&u8>>| ());
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::{ffi::with_zeroed_out, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct HasReference<'a> {
+///     a: Option<&'a u8>,
+/// }
+///
+/// let _ = with_zeroed_out(|_: *mut HasReference<'static>| ());
+/// ```
+///
+/// ```rust
+/// use zeroable::{
+///     ffi::{with_zeroed_out, ZeroedOut},
+///     Zeroable,
+/// };
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct IsReprC {
+///     a: u32,
+/// }
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct GenericReprC<T> {
+///     a: T,
+/// }
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct HasReference<'a> {
+///     a: Option<&'a u8>,
+/// }
+///
+/// let _ = with_zeroed_out(|_: *mut IsReprC| ());
+/// let _ = with_zeroed_out(|_: *mut GenericReprC<u32>| ());
+/// let _ = HasReference::zeroed();
+///
+/// let mut value = 3_u32;
+/// let _ = ZeroedOut::new(&mut value);
+/// ```
+pub struct FfiNonCompiling;
+
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(size_field="size")]
+/// struct FloatSize {
+///     size: f32,
+///     value: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(init(kind="\"hello\""))]
+/// struct MismatchedInit {
+///     kind: u32,
+///     value: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(size_field="size")]
+/// struct MissingField {
+///     len: u32,
+/// }
+/// ```
+///
+/// ```rust
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(size_field="size",init(kind="3"))]
+/// struct Compiles {
+///     size: u32,
+///     kind: u32,
+/// }
+///
+/// let _ = Compiles::new_zeroed();
+/// ```
+pub struct NewZeroedNonCompiling;
+
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(c_header)]
+/// struct HasReference {
+///     value: u32,
+///     name: Option<&'static u8>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// struct NotInHeader {
+///     value: u32,
+/// }
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(c_header)]
+/// struct Outer {
+///     inner: NotInHeader,
+/// }
+/// ```
+///
+/// ```rust
+/// use zeroable::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(c_header)]
+/// struct InHeader {
+///     value: u32,
+/// }
+///
+/// #[derive(Zeroable)]
+/// #[repr(C)]
+/// #[zero(c_header)]
+/// struct Outer {
+///     inner: InHeader,
+///     ptr: *const InHeader,
+/// }
+/// ```
+pub struct CHeaderNonCompiling;
+
+/// The generic form of `assert_not_zeroable` fails when the bounds
+/// imply that the type is `Zeroable`.
+///
+/// ```compile_fail
+/// use zeroable::{assert_not_zeroable, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_not_zeroable!(impl[T] Foo<T> where T: Zeroable);
+/// ```
+///
+/// ```compile_fail
+/// use zeroable::{assert_not_zeroable, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// // `Option<&T>` is zeroable for every `T`.
+/// assert_not_zeroable!(impl[T: 'static] Foo<Option<&'static T>>);
+/// ```
+///
+/// ```rust
+/// use zeroable::{assert_not_zeroable, Zeroable};
+///
+/// #[derive(Zeroable)]
+/// struct Foo<T>{
+///     value:T,
+/// }
+///
+/// assert_not_zeroable!(impl[T] Foo<T> where T: Clone);
+/// assert_not_zeroable!(Foo<&'static str>);
+/// ```
+pub struct AssertNotZeroableGenericNonCompiling;
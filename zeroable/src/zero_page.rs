@@ -0,0 +1,140 @@
+//! Functions for getting references to zeroed values,
+//! backed by a single static buffer of zero bytes.
+//!
+//! The functions here require the referenced type to be `Copy`,
+//! because `Copy` types can't have interior mutability
+//! (which would allow mutating the shared buffer through a shared reference).
+
+use crate::Zeroable;
+
+use core::{
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    slice,
+};
+
+/// The size in bytes of the static buffer of zeroes.
+///
+/// This is the maximum size of the types that `zeroed_ref` can return a reference to.
+pub const ZERO_PAGE_SIZE: usize = 4096;
+
+/// The alignment of the static buffer of zeroes.
+///
+/// This is the maximum alignment of the types that
+/// `zeroed_ref` and `zeroed_slice_ref` can return references to.
+pub const ZERO_PAGE_ALIGN: usize = 4096;
+
+#[repr(C, align(4096))]
+struct ZeroPage([u8; ZERO_PAGE_SIZE]);
+
+static ZERO_PAGE: ZeroPage = ZeroPage([0; ZERO_PAGE_SIZE]);
+
+struct FitsInZeroPage<T>(PhantomData<T>);
+
+impl<T> FitsInZeroPage<T> {
+    const SIZE: () = assert!(
+        size_of::<T>() <= ZERO_PAGE_SIZE,
+        "The type is too large to be referenced in the zero page",
+    );
+
+    const ALIGN: () = assert!(
+        align_of::<T>() <= ZERO_PAGE_ALIGN,
+        "The type's alignment is too large to be referenced in the zero page",
+    );
+}
+
+/// Gets a reference to a zeroed `T`.
+///
+/// This doesn't allocate,
+/// all references returned by this function point to the same static buffer.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if `T` is larger than
+/// [`ZERO_PAGE_SIZE`](./constant.ZERO_PAGE_SIZE.html),
+/// or if its alignment is larger than
+/// [`ZERO_PAGE_ALIGN`](./constant.ZERO_PAGE_ALIGN.html).
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::{zero_page::zeroed_ref, Zeroable};
+///
+/// #[derive(Debug, PartialEq, Copy, Clone, Zeroable)]
+/// struct Config {
+///     retries: u32,
+///     timeout_ms: u64,
+///     verbose: bool,
+/// }
+///
+/// let config: &'static Config = zeroed_ref();
+///
+/// assert_eq!( *config, Config{ retries: 0, timeout_ms: 0, verbose: false } );
+/// assert_eq!( zeroed_ref::<[u64; 16]>(), &[0; 16] );
+///
+/// ```
+///
+/// This doesn't compile because the type is larger than the zero page:
+///
+/// ```compile_fail
+/// use zeroable::{zero_page::zeroed_ref, Zeroable};
+///
+/// #[derive(Copy, Clone, Zeroable)]
+/// struct Big([u64; 512], [u64; 512]);
+///
+/// let _ = zeroed_ref::<Big>();
+/// ```
+#[inline]
+pub fn zeroed_ref<T>() -> &'static T
+where
+    T: Zeroable + Copy + Sync,
+{
+    let () = FitsInZeroPage::<T>::SIZE;
+    let () = FitsInZeroPage::<T>::ALIGN;
+    unsafe { &*(ZERO_PAGE.0.as_ptr() as *const T) }
+}
+
+/// Gets a slice of `len` zeroed `T`s.
+///
+/// This doesn't allocate,
+/// all slices returned by this function point to the same static buffer.
+///
+/// # Panics
+///
+/// This panics if the slice would be larger than
+/// [`ZERO_PAGE_SIZE`](./constant.ZERO_PAGE_SIZE.html) bytes.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the alignment of `T` is larger than
+/// [`ZERO_PAGE_ALIGN`](./constant.ZERO_PAGE_ALIGN.html).
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::zero_page::zeroed_slice_ref;
+///
+/// assert_eq!( zeroed_slice_ref::<u32>(4), &[0, 0, 0, 0] );
+/// assert_eq!( zeroed_slice_ref::<(u8, u16)>(2), &[(0, 0), (0, 0)] );
+/// assert_eq!( zeroed_slice_ref::<()>(1_000_000).len(), 1_000_000 );
+///
+/// ```
+#[inline]
+pub fn zeroed_slice_ref<T>(len: usize) -> &'static [T]
+where
+    T: Zeroable + Copy + Sync,
+{
+    let () = FitsInZeroPage::<T>::ALIGN;
+
+    match size_of::<T>().checked_mul(len) {
+        Some(size) if size <= ZERO_PAGE_SIZE => {}
+        _ => panic!(
+            "A slice of {} elements of {} bytes doesn't fit in the {} bytes of the zero page",
+            len,
+            size_of::<T>(),
+            ZERO_PAGE_SIZE,
+        ),
+    }
+
+    unsafe { slice::from_raw_parts(ZERO_PAGE.0.as_ptr() as *const T, len) }
+}
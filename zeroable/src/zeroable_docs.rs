@@ -60,27 +60,216 @@ These are all the attributes for the derive macro,grouped by where they can be u
 
 Adds a contraint to the `Zeroable` impl.
 
+##### `#[zero(bound(TypeA:ATrait,TypeB::Assoc:OtherTrait))]`
+
+Adds one/many constraints to the `Zeroable` impl,
+parsing them as where predicates instead of a string.
+
+##### `#[zero(bound="")]`
+
+Removes the default `Zeroable` bound for all the type parameters,
+so that only the constraints from the other `#[zero(bound...)]` attributes are added.
+
 ##### `#[zero(not_zeroable(TypeParamA,TypeParamB,TypeParamC))]`
 
 Removes the default `Zeroable` bound for one/many type parameters.
 
+##### `#[zero(allow(warning_a,warning_b))]`
+
+Disables the [warnings](#warnings) listed in the attribute.
+
 ##### `#[zero(nonzero_fields)]`
 
-For unions only.
+For unions,and structs with the `#[zero(with_rest)]` attribute.
 
 Marks all the fields as not being zeroable,
 removing the assertion that they implement Zeroable,
-requiring some fields to have a `#[zero(zeroable)]` attribute.
+requiring some fields to have a `#[zero(zeroable)]` attribute (for unions).
+
+##### `#[zero(with_rest)]`
+
+For structs only.
+
+Generates a `zeroed_with` constructor,
+which takes the fields with the `#[zero(nonzero)]` attribute as parameters
+(in declaration order),and zeroes all the other fields.
+
+The fields that are zeroed are required to implement Zeroable,
+while the `#[zero(nonzero)]` fields aren't,
+so `zeroed_with` only requires the type parameters used by the zeroed fields to be Zeroable.
+
+If any field has the `#[zero(nonzero)]` attribute,the struct doesn't implement Zeroable.
+
+##### `#[zero(size_field="field_name")]`
+
+For structs only.
+
+Generates a `new_zeroed` constructor,with the same visibility as the struct,
+which zeroes every field,and then sets the `field_name` field to the size of the struct,
+for C structs that are versioned by their size (eg:the `cbSize` field of Win32 structs).
+
+The field must have an integer type,`new_zeroed` panics if the size doesn't fit in it.
+
+For tuple structs,the name of the field is its position,eg:`#[zero(size_field="0")]`.
+
+##### `#[zero(init(field_a="expression",field_b="expression"))]`
+
+For structs only.
+
+Generates the same `new_zeroed` constructor as `#[zero(size_field="...")]`,
+which sets every listed field to the value of its expression,
+for C structs that require a field to be set to a specific value
+(eg:the `sType` field of Vulkan structs).
+
+The expressions must have the same type as the field.
+
+These attributes can't be combined with `#[zero(with_rest)]`.
+
+Example:
+
+```rust
+use zeroable::Zeroable;
+
+#[derive(Debug,Copy,Clone,PartialEq,Zeroable)]
+#[repr(transparent)]
+pub struct StructureType(u32);
+
+impl StructureType{
+    pub const DEVICE_INFO: Self = StructureType(9);
+}
+
+#[derive(Zeroable)]
+#[repr(C)]
+#[zero(size_field="cb_size",init(s_type="StructureType::DEVICE_INFO"))]
+pub struct DeviceInfo{
+    pub cb_size: u32,
+    pub s_type: StructureType,
+    pub flags: u32,
+    pub name: [u8; 32],
+}
+
+let info = DeviceInfo::new_zeroed();
+assert_eq!( info.cb_size as usize, std::mem::size_of::<DeviceInfo>() );
+assert_eq!( info.s_type, StructureType::DEVICE_INFO );
+assert_eq!( info.flags, 0 );
+assert_eq!( info.name, [0; 32] );
+```
+
+##### `#[zero(remote="path::to::Type")]`
+
+For structs and unions without generic parameters.
+
+Declares that this type is a mirror of a type defined elsewhere
+(usually in a `-sys` crate),
+implementing `Zeroable` for the foreign type.
+
+This asserts at compile-time that:
+
+- The mirror and the foreign type have the same size and alignment.
+
+- Every field has the same offset in the mirror and in the foreign type.
+
+- Every field has exactly the same type in the mirror and in the foreign type.
+
+- The mirror declares all the fields of the foreign type (for structs only).
+
+Since the `Zeroable` trait is defined in another crate,
+the impl for the foreign type is only allowed if it is defined in the current crate,
+otherwise use the `#[zero(remote_newtype="...")]` attribute.
+
+This attribute requires Rust 1.77,because it uses `core::mem::offset_of`.
+
+##### `#[zero(remote_newtype="Name")]`
+
+Requires the `#[zero(remote="...")]` attribute.
+
+Instead of implementing `Zeroable` for the foreign type,
+declares a `#[repr(transparent)]` newtype wrapping it,
+with the same visibility as the mirror,which implements `Zeroable`.
+
+##### `#[zero(variant_info)]`
+
+For enums only.
+
+Generates these inherent items,with the same visibility as the enum:
+
+- `const ZERO_VARIANT_NAME: &'static str`:
+The name of the variant that `Zeroable::zeroed` returns.
+
+- `fn is_zero_variant(&self) -> bool`:
+Whether `self` is the variant that `Zeroable::zeroed` returns,
+ignoring the values of its fields.
+
+##### `#[zero(layout_info)]`
+
+For structs and unions.
+
+Implements the [`ZeroableLayout`](../layout/trait.ZeroableLayout.html) trait,
+whose `ZEROABLE_LAYOUT` associated constant describes every field:
+its name,its type (as written in the type definition),its offset,its size,
+and whether it's required to be zeroable
+(it's not for the fields with the `#[zero(nonzero)]` attribute).
+
+This attribute requires Rust 1.77,because it uses `core::mem::offset_of`.
+
+##### `#[zero(c_header)]`
+
+For `#[repr(C)]` structs and unions without type or const parameters.
+
+Implements the [`CType`](../c_header/trait.CType.html) and
+[`CHeaderType`](../c_header/trait.CHeaderType.html) traits,
+which record the layout of the type,
+so that its definition can be written to a C header with
+[`CHeader`](../c_header/struct.CHeader.html) (usually from a build script),
+along with a `#define NAME_ZERO_INIT {0}` zero initializer,
+and `_Static_assert`s that its size,alignment,and field offsets match the Rust type.
+
+Every field must have a type that implements `CType`,
+which includes integers,floats,`bool`,raw pointers,arrays,
+and types that use this attribute.
+
+The fields of tuple structs are named by their position prefixed with `_`,eg:`_0`.
+
+This attribute requires Rust 1.77,because it uses `core::mem::offset_of`.
 
 ##### `#[zero(debug_print)]`
 
-Prints the generated code,stopping compilation.
+Prints the formatted generated code in a compile-time error,stopping compilation.
+
+`#[zero(debug_print="error")]` is equivalent to `#[zero(debug_print)]`.
+
+##### `#[zero(debug_print="file")]`
+
+Writes the formatted generated code to a
+`zeroable-expand/<crate>/<Type>.<Derive>.rs` file,
+without stopping compilation,
+eg:`zeroable-expand/my_crate/Point.Zeroable.rs`
+for `#[derive(Zeroable)] struct Point{...}` in the `my_crate` crate.
+
+The file is overwritten every time that the type is compiled,
+so the attribute can be left on the type while changing it.
+
+If another type with the same name in the same crate also uses this attribute
+(eg:in a different module),
+its output is written to a `<Type>.<Derive>.<hash>.rs` file instead,
+where the hash is computed from the definition of the type.
+
+The `zeroable-expand` directory is created inside the first of these directories:
+
+- `$OUT_DIR`,for crates with a build script.
+
+- `$CARGO_TARGET_DIR`,if that environment variable is set.
+
+- The closest `target` directory of the crate or one of its ancestors
+  (usually the target directory of the workspace).
+
+This attribute is also supported by the `Reset` and `IsZero` derives.
 
 ## Field attributes
 
 ##### `#[zero(zeroable)]`
 
-For unions only.
+For unions,and structs with the `#[zero(with_rest)]` attribute.
 
 Marks the field as being initializable with zeroes,
 adding an assertion that it implements Zeroable.
@@ -90,14 +279,159 @@ the Zeroable impl under `Zeroable Fields`.
 
 ##### `#[zero(nonzero)]`
 
-For unions only.
+For unions,and structs with the `#[zero(with_rest)]` attribute.
 
 Marks the field as not being initializable with zeroes,
 removing the assertion that it implements Zeroable.
 
-The field is then mentioned in the generated documentation for
+For unions,the field is then mentioned in the generated documentation for
 the Zeroable impl under `NonZero Fields`.
 
+For structs,the field becomes a parameter of the `zeroed_with` constructor.
+
+##### `#[zero(keep)]`
+
+For struct and enum fields.
+
+Used by the [`Reset`](../reset/trait.Reset.html) derive macro,
+it makes `Reset::reset` leave the field unchanged.
+
+This attribute is ignored by the `Zeroable` derive macro.
+
+##### `#[zero(bound="Type:ATrait")]`/`#[zero(bound(TypeA:ATrait,TypeB:OtherTrait))]`
+
+Adds constraints to the impl that only apply if the field is zeroed.
+
+For enums,the constraints are only added for fields of the zero variant.
+
+For unions,and structs with the `#[zero(with_rest)]` attribute,
+the constraints are only added for fields that are required to be zeroable.
+
+
+## Warnings
+
+The derive warns about attributes that are redundant or have no effect.
+Since derive macros can't emit warnings on stable Rust,
+these are reported as uses of deprecated items,
+pointing at the attribute that caused them.
+
+These warnings can be disabled with the `#[zero(allow(...))]` attribute:
+
+- `ineffective_not_zeroable`:
+  A type parameter is listed in `#[zero(not_zeroable(...))]`,
+  but it's still required to be zeroable by a field that must be zeroable,
+  eg:a `T` or `[T; 4]` field in a struct,or in the zero variant of an enum.
+
+- `redundant_nonzero`:
+  A field has the `#[zero(nonzero)]` attribute,but its type implements `Zeroable`.
+
+- `redundant_bound`:
+  A `#[zero(bound="T: Zeroable")]` attribute is redundant,
+  because `T` is already required to be `Zeroable` automatically.
+
+Example:
+
+```rust
+use zeroable::Zeroable;
+
+#[derive(Zeroable)]
+#[zero(with_rest,allow(redundant_nonzero))]
+struct Request{
+    // This is zeroable,but it should be passed to `Request::zeroed_with`.
+    #[zero(nonzero)]
+    id: u64,
+    buffer: [u8; 16],
+}
+
+let request = Request::zeroed_with(10);
+assert_eq!( request.id, 10 );
+```
+
+# FFI
+
+The derive implements the [`ReprC`](../ffi/trait.ReprC.html) trait
+for `#[repr(C)]` types,and for enums with an integer representation (eg:`#[repr(u8)]`),
+which the helpers for zeroed out-parameters in the [`ffi`](../ffi/index.html) module require.
+The impl requires the types of all the fields to implement `ReprC`,
+so a `#[repr(C)]` struct with a reference field doesn't implement `ReprC`.
+
+Example:
+
+```rust
+use zeroable::{ffi::with_zeroed_out, Zeroable};
+
+#[derive(Zeroable)]
+#[repr(C)]
+struct Info{
+    size: u32,
+    flags: u32,
+}
+
+let (_, info) = with_zeroed_out(|out: *mut Info| unsafe{ (*out).size = 8; });
+assert_eq!( info.size, 8 );
+assert_eq!( info.flags, 0 );
+```
+
+The [`#[zero(c_header)]`](#zeroc_header) attribute
+records the layout of a `#[repr(C)]` type to write it to a C header,
+with the [`c_header`](../c_header/index.html) module.
+
+# Migrating from bytemuck_derive
+
+The `Zeroable` derive also accepts the `#[zeroable(bound="...")]` attribute
+from bytemuck_derive,with the same meaning as in bytemuck_derive,
+so that types can switch derives without changing their attributes.
+
+##### `#[zeroable(bound="TypeA:ATrait,TypeB:OtherTrait")]`
+
+Removes the default `Zeroable` bound for all the type parameters,
+requiring the types of the zeroed fields that use type parameters to be `Zeroable` instead,
+and adds the constraints in the string to the `Zeroable` impl.
+
+This is not the same as `#[zero(bound="...")]`,
+which keeps the default `Zeroable` bounds for type parameters.
+The `#[zero(...)]` equivalent of `#[zeroable(bound="T: Copy")]` is
+`#[zero(bound="",bound="T: Copy")]` with a
+`#[zero(bound="FieldType: Zeroable")]` attribute on every generic field.
+
+The `#[zeroable(...)]` attribute only supports `bound`,
+and can only be used on the type.
+
+### Differences
+
+These are the differences with bytemuck_derive to keep in mind when migrating:
+
+- Replacing `#[zeroable(bound="...")]` with `#[zero(bound="...")]` changes the bounds
+  of the impl,as described above.
+
+- The zeroed fields whose types are bounded by `#[zeroable(bound="...")]` are:
+  all the fields of structs,
+  the fields of the zero variant of enums,
+  and the fields of unions that don't have the `#[zero(nonzero)]` attribute.
+
+- Union fields are all required to be `Zeroable`,like in bytemuck_derive,
+  unless they have the `#[zero(nonzero)]` attribute
+  (or the union has the `#[zero(nonzero_fields)]` attribute).
+
+- Enums must have the `repr` attribute and zero variant described in [Enums](#enums).
+
+Example:
+
+```rust
+use zeroable::Zeroable;
+
+use core::{marker::PhantomData,num::NonZeroU8};
+
+#[derive(Zeroable)]
+#[zeroable(bound="")]
+struct AlwaysZeroable<T>{
+    a: PhantomData<T>,
+}
+
+let _ = AlwaysZeroable::<NonZeroU8>::zeroed();
+
+```
+
 # Examples
 
 ### Enum
@@ -168,6 +502,58 @@ assert_eq!( Ordering::zeroed(), Ordering::Equal );
 
 ```
 
+### Enum (variant_info)
+
+A state machine that checks whether it's still in its initial state,
+without requiring the payloads to implement `PartialEq`.
+
+```rust
+use zeroable::Zeroable;
+
+#[derive(Zeroable)]
+#[repr(u8)]
+#[zero(variant_info,not_zeroable(S))]
+enum Connection<S>{
+    Idle{ retries: u32 },
+    Connected(S),
+}
+
+assert_eq!( Connection::<std::net::TcpStream>::ZERO_VARIANT_NAME, "Idle" );
+
+let mut conn = Connection::<std::net::TcpStream>::zeroed();
+assert!( conn.is_zero_variant() );
+
+conn = Connection::Idle{ retries: 3 };
+assert!( conn.is_zero_variant() );
+
+```
+
+### Enum (field bounds)
+
+An enum where only the zero variant requires its payload to be zeroable,
+and the bound on the field of the `Boxed` variant is not added.
+
+```rust
+use zeroable::Zeroable;
+
+#[derive(Debug,PartialEq,Zeroable)]
+#[repr(u8)]
+#[zero(bound="")]
+enum Slot<T,U>{
+    Inline(
+        #[zero(bound(T: Zeroable))]
+        T,
+    ),
+    Boxed(
+        #[zero(bound="U: Zeroable")]
+        Box<U>,
+    ),
+}
+
+assert_eq!( Slot::<u32,String>::zeroed(), Slot::Inline(0) );
+
+```
+
 ### Enum (non-compiling)
 
 This doesn't compile because there is no variant with a `0` discriminant.
@@ -344,6 +730,44 @@ assert_eq!(
 
 ```
 
+### Struct (with_rest)
+
+Here we construct a struct that isn't zeroable,
+because it has a few fields that can't be zeroed.
+
+```rust
+use zeroable::Zeroable;
+
+use core::num::NonZeroU32;
+
+#[derive(Debug,PartialEq,Zeroable)]
+#[zero(with_rest)]
+struct Connection{
+    #[zero(nonzero)]
+    id:NonZeroU32,
+    #[zero(nonzero)]
+    buffer:Box<[u8]>,
+    sent:u64,
+    received:u64,
+    retries:[u16;4],
+}
+
+let id=NonZeroU32::new(7).unwrap();
+let buffer=vec![0;16].into_boxed_slice();
+
+assert_eq!(
+    Connection::zeroed_with(id, buffer.clone()),
+    Connection{
+        id,
+        buffer,
+        sent:0,
+        received:0,
+        retries:[0;4],
+    },
+);
+
+```
+
 ### Struct (non-compiling)
 
 This doesn't compile because `&[T]` is not zeroable.
@@ -358,6 +782,67 @@ struct NonEmptySlice<'a,T>{
 
 ```
 
+### Remote
+
+Implementing `Zeroable` for a type defined in another crate,
+by mirroring its definition.
+
+```rust
+mod ffi {
+    #[repr(C)]
+    pub struct Rect {
+        pub x: u32,
+        pub y: u32,
+        pub w: u16,
+        pub h: u16,
+    }
+}
+
+use zeroable::Zeroable;
+
+#[derive(Zeroable)]
+#[zero(remote="ffi::Rect", remote_newtype="ZeroableRect")]
+#[repr(C)]
+struct RectMirror {
+    x: u32,
+    y: u32,
+    w: u16,
+    h: u16,
+}
+
+let ZeroableRect(rect) = ZeroableRect::zeroed();
+assert_eq!( (rect.x, rect.y, rect.w, rect.h), (0, 0, 0, 0) );
+
+```
+
+### Remote (non-compiling)
+
+This doesn't compile because the `h` field has a different type in the mirror.
+
+```compile_fail
+mod ffi {
+    #[repr(C)]
+    pub struct Rect {
+        pub x: u32,
+        pub y: u32,
+        pub w: u16,
+        pub h: u16,
+    }
+}
+
+use zeroable::Zeroable;
+
+#[derive(Zeroable)]
+#[zero(remote="ffi::Rect", remote_newtype="ZeroableRect")]
+#[repr(C)]
+struct RectMirror {
+    x: u32,
+    y: u32,
+    w: u16,
+    h: i16,
+}
+```
+
 ### Union
 
 ```rust
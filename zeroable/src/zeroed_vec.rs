@@ -0,0 +1,388 @@
+//! Contains the `ZeroedVec` type,a growable array whose spare capacity is always zeroed.
+
+use crate::Zeroable;
+
+use alloc::{
+    alloc::{alloc_zeroed, dealloc, handle_alloc_error, realloc, Layout},
+    vec::Vec,
+};
+
+use core::{
+    cmp, fmt,
+    marker::PhantomData,
+    mem::{self, size_of},
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+/// A growable array of `T`s,whose spare capacity is always zeroed.
+///
+/// Because the spare capacity is zeroed,and `T` is `Zeroable`,
+/// the vector can be grown without writing the new elements,
+/// with [`grow_to`](#method.grow_to) and [`get_or_grow_mut`](#method.get_or_grow_mut).
+///
+/// Memory is allocated with `alloc_zeroed`,
+/// and when the vector reallocates,the new part of the allocation is zeroed.
+/// Removing elements zeroes the memory they occupied.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable::zeroed_vec::ZeroedVec;
+///
+/// // A sparse table,indexed by id.
+/// let mut hits = ZeroedVec::<u32>::new();
+///
+/// *hits.get_or_grow_mut(10) += 1;
+/// *hits.get_or_grow_mut(3) += 1;
+/// *hits.get_or_grow_mut(10) += 1;
+///
+/// assert_eq!(hits.len(), 11);
+/// assert_eq!(hits[10], 2);
+/// assert_eq!(hits[3], 1);
+/// assert_eq!(hits[0], 0);
+///
+/// hits.truncate_and_zero(4);
+/// assert_eq!(hits[..], [0, 0, 0, 1]);
+///
+/// hits.grow_to(6);
+/// assert_eq!(hits[..], [0, 0, 0, 1, 0, 0]);
+///
+/// ```
+pub struct ZeroedVec<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    capacity: usize,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for ZeroedVec<T> {}
+
+unsafe impl<T: Sync> Sync for ZeroedVec<T> {}
+
+// `new` doesn't have a `T: Zeroable` bound,
+// because trait bounds on `const fn`s require Rust 1.61.
+impl<T> ZeroedVec<T> {
+    /// Constructs an empty `ZeroedVec`,without allocating.
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            capacity: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    /// Constructs an empty `ZeroedVec` with space for at least `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the allocation overflows `isize`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut this = Self::new();
+        this.reserve(capacity);
+        this
+    }
+
+    /// The amount of elements in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the vector has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The amount of elements the vector can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Gets the elements as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Reserves space for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the allocation overflows `isize`.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .len
+            .checked_add(additional)
+            .expect("ZeroedVec capacity overflow");
+        if required > self.capacity {
+            let new_capacity = cmp::max(cmp::max(self.capacity * 2, required), 4);
+            self.reallocate(new_capacity);
+        }
+    }
+
+    /// Appends `value` to the end of the vector.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.reserve(1);
+        }
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) }
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it,zeroing the memory it occupied.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let last = self.ptr.as_ptr().add(self.len);
+            let value = last.read();
+            ptr::write_bytes(last, 0, 1);
+            Some(value)
+        }
+    }
+
+    /// Grows the vector to `len` elements,the new elements are zeroed.
+    ///
+    /// This does nothing if the vector already has `len` or more elements.
+    ///
+    /// This doesn't write the new elements,since the spare capacity is already zeroed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the allocation overflows `isize`.
+    pub fn grow_to(&mut self, len: usize) {
+        if len > self.len {
+            self.reserve(len - self.len);
+            // Safety: the spare capacity is zeroed,and a zeroed `T` is valid.
+            self.len = len;
+        }
+    }
+
+    /// Gets a mutable reference to the element at `index`,
+    /// growing the vector with zeroed elements if `index` is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the allocation overflows `isize`.
+    pub fn get_or_grow_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len {
+            self.grow_to(index.checked_add(1).expect("ZeroedVec capacity overflow"));
+        }
+        unsafe { &mut *self.ptr.as_ptr().add(index) }
+    }
+
+    /// Shortens the vector to `len` elements,
+    /// dropping the removed elements and zeroing the memory they occupied.
+    ///
+    /// This does nothing if the vector has `len` or fewer elements.
+    pub fn truncate_and_zero(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        /// Zeroes the removed elements when dropped,
+        /// so that they're zeroed even if one of their destructors panics.
+        struct ZeroOnDrop<T> {
+            removed: *mut T,
+            removed_len: usize,
+        }
+
+        impl<T> Drop for ZeroOnDrop<T> {
+            fn drop(&mut self) {
+                unsafe { ptr::write_bytes(self.removed, 0, self.removed_len) }
+            }
+        }
+
+        let removed_len = self.len - len;
+        // Setting the length first so that the elements aren't dropped twice
+        // if a destructor panics.
+        self.len = len;
+        let guard = ZeroOnDrop {
+            removed: unsafe { self.ptr.as_ptr().add(len) },
+            removed_len,
+        };
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(guard.removed, removed_len));
+        }
+    }
+
+    /// Removes every element,
+    /// dropping them and zeroing the memory they occupied.
+    pub fn clear(&mut self) {
+        self.truncate_and_zero(0);
+    }
+
+    /// Converts this into a `Vec<T>`,without reallocating.
+    ///
+    /// The spare capacity of the returned `Vec` is zeroed,
+    /// but `Vec` doesn't keep it zeroed.
+    pub fn into_vec(self) -> Vec<T> {
+        let this = mem::ManuallyDrop::new(self);
+        // Safety: the memory was allocated by the global allocator,
+        // with the layout of an array of `capacity` `T`s,
+        // and for zero-sized `T`s the pointer is dangling and the capacity is ignored.
+        unsafe { Vec::from_raw_parts(this.ptr.as_ptr(), this.len, this.capacity) }
+    }
+
+    fn reallocate(&mut self, new_capacity: usize) {
+        let new_layout = Layout::array::<T>(new_capacity).expect("ZeroedVec capacity overflow");
+        let ptr = if self.capacity == 0 {
+            unsafe { alloc_zeroed(new_layout) }
+        } else {
+            unsafe {
+                let old_layout = Layout::array::<T>(self.capacity).unwrap();
+                let ptr = realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size());
+                if !ptr.is_null() {
+                    let old_size = old_layout.size();
+                    ptr::write_bytes(ptr.add(old_size), 0, new_layout.size() - old_size);
+                }
+                ptr
+            }
+        };
+        self.ptr = match NonNull::new(ptr as *mut T) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(new_layout),
+        };
+        self.capacity = new_capacity;
+    }
+}
+
+impl<T> Drop for ZeroedVec<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            if size_of::<T>() != 0 && self.capacity != 0 {
+                let layout = Layout::array::<T>(self.capacity).unwrap();
+                dealloc(self.ptr.as_ptr() as *mut u8, layout);
+            }
+        }
+    }
+}
+
+impl<T> Default for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Clone for ZeroedVec<T>
+where
+    T: Zeroable + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut cloned = Self::with_capacity(self.len);
+        for elem in self.iter() {
+            cloned.push(elem.clone());
+        }
+        cloned
+    }
+}
+
+impl<T> Extend<T> for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<T> core::iter::FromIterator<T> for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<T> fmt::Debug for ZeroedVec<T>
+where
+    T: Zeroable + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T, U> PartialEq<U> for ZeroedVec<T>
+where
+    T: Zeroable + PartialEq,
+    U: ?Sized + AsRef<[T]>,
+{
+    fn eq(&self, other: &U) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+impl<T> AsRef<[T]> for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for ZeroedVec<T>
+where
+    T: Zeroable,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
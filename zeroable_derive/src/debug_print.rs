@@ -0,0 +1,154 @@
+//! Printing the output of the derive macros,for the `#[zero(debug_print)]` attribute.
+
+use zeroable_derive_internals::{attribute_parsing::DebugPrint, datastructure::DataStructure};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::ToTokens;
+
+use std::{
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, HashMap},
+    env, fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+/// Prints `tokens` (the output of the `derive_name` derive macro) as specified by `mode`.
+///
+/// The returned tokens always include `tokens`,
+/// so that printing doesn't cause spurious errors in code that uses the generated items.
+pub(crate) fn debug_print(
+    ds: &'_ DataStructure<'_>,
+    derive_name: &str,
+    mode: DebugPrint,
+    tokens: TokenStream2,
+) -> Result<TokenStream2, syn::Error> {
+    let pretty = pretty_print(&tokens);
+
+    match mode {
+        DebugPrint::Error => {
+            let error = spanned_err! {
+                ds.name,
+                "\n`{}` derive output for `{}`:\n\n{}",
+                derive_name,
+                ds.name,
+                pretty,
+            };
+            let error = error.to_compile_error();
+            Ok(quote::quote!( #tokens #error ))
+        }
+        DebugPrint::File => {
+            let dir = expansion_dir();
+            let file = dir.join(expansion_file_name(ds, derive_name, &dir));
+
+            fs::create_dir_all(&dir)
+                .and_then(|_| fs::write(&file, pretty))
+                .map_err(|e| {
+                    spanned_err! {
+                        ds.name,
+                        "Could not write the `{}` derive output to `{}`:\n{}",
+                        derive_name,
+                        file.display(),
+                        e,
+                    }
+                })?;
+
+            Ok(tokens)
+        }
+    }
+}
+
+/// Formats `tokens` as Rust source code,
+/// falling back to the unformatted tokens if they can't be parsed as a file.
+fn pretty_print(tokens: &TokenStream2) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => tokens.to_string(),
+    }
+}
+
+/// The name of the file that the `derive_name` output for the `ds` type is written to,
+/// `<Type>.<Derive>.rs`,which is overwritten every time that the type is compiled.
+///
+/// If a type with the same name but a different definition already had its output
+/// written to `dir` by this thread (eg:a type in another module of the same crate),
+/// the name is disambiguated with a hash of the definition,`<Type>.<Derive>.<hash>.rs`.
+fn expansion_file_name(ds: &'_ DataStructure<'_>, derive_name: &str, dir: &Path) -> String {
+    thread_local! {
+        /// The hashes of the definitions of the types whose output was written to each file.
+        static WRITTEN: RefCell<HashMap<PathBuf, u64>> = RefCell::new(HashMap::new());
+    }
+
+    let name = format!("{}.{}.rs", ds.name, derive_name);
+    let definition_hash = definition_hash(ds);
+
+    let prev_hash = WRITTEN.with(|written| {
+        *written
+            .borrow_mut()
+            .entry(dir.join(&name))
+            .or_insert(definition_hash)
+    });
+
+    if prev_hash == definition_hash {
+        name
+    } else {
+        format!("{}.{}.{:016x}.rs", ds.name, derive_name, definition_hash)
+    }
+}
+
+/// Hashes the generic parameters,variants,and fields of the type.
+fn definition_hash(ds: &'_ DataStructure<'_>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    ds.generics.to_token_stream().to_string().hash(&mut hasher);
+    for variant in &ds.variants {
+        variant.name.to_string().hash(&mut hasher);
+        for field in &variant.fields {
+            field.ident.to_string().hash(&mut hasher);
+            field.ty.to_token_stream().to_string().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// The directory that the output of the crate being compiled is written to,
+/// `<expand_dir>/<crate_name>`,
+/// so that types with the same name in different crates don't overwrite each other.
+pub(crate) fn expansion_dir() -> PathBuf {
+    let dir = expand_dir();
+    match env::var_os("CARGO_CRATE_NAME") {
+        Some(crate_name) => dir.join(crate_name),
+        None => dir,
+    }
+}
+
+/// The directory that the output is written to,which is the first of:
+///
+/// - `$OUT_DIR/zeroable-expand`,for crates with a build script.
+///
+/// - `$CARGO_TARGET_DIR/zeroable-expand`.
+///
+/// - `<dir>/target/zeroable-expand`,where `<dir>` is the closest ancestor
+///   of the crate's directory with a `target` directory (usually the workspace root).
+///
+/// - `./target/zeroable-expand`.
+fn expand_dir() -> PathBuf {
+    const SUBDIR: &str = "zeroable-expand";
+
+    if let Some(out_dir) = env::var_os("OUT_DIR") {
+        return Path::new(&out_dir).join(SUBDIR);
+    }
+
+    if let Some(target_dir) = env::var_os("CARGO_TARGET_DIR") {
+        return Path::new(&target_dir).join(SUBDIR);
+    }
+
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map_or_else(PathBuf::new, PathBuf::from);
+
+    manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("target"))
+        .find(|target| target.is_dir())
+        .unwrap_or_else(|| manifest_dir.join("target"))
+        .join(SUBDIR)
+}
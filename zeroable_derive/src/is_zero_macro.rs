@@ -0,0 +1,97 @@
+use crate::{
+    debug_print::debug_print,
+    zeroable_macro::{attribute_parsing, bounded_where_clause},
+};
+
+use zeroable_derive_internals::{
+    datastructure::{DataStructure, DataVariant, Struct},
+    zero_variant::zero_variant_index,
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, quote_spanned};
+
+use syn::DeriveInput;
+
+pub fn derive(data: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let ds = &DataStructure::new(&data);
+
+    let config = &attribute_parsing::parse_attrs_for_zeroed(ds)?;
+
+    let zero_variant = match ds.data_variant {
+        DataVariant::Struct => &ds.variants[0],
+        DataVariant::Enum => &ds.variants[zero_variant_index(ds, config.repr_attr())?],
+        DataVariant::Union => {
+            return_spanned_err! { ds.name,"IsZero cannot be derived for unions." }
+        }
+    };
+
+    let name = ds.name;
+
+    let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+
+    let zeroed_fields = zero_variant.fields.iter().collect::<Vec<_>>();
+
+    let where_clause_tokens =
+        bounded_where_clause(ds, config, &zeroed_fields, &quote!(::zeroable::IsZero));
+
+    let zero_arm = emit_zero_arm(ds, zero_variant);
+
+    let other_arms = if ds.data_variant == DataVariant::Enum && ds.variants.len() > 1 {
+        quote!( _ => false, )
+    } else {
+        TokenStream2::new()
+    };
+
+    let tokens = quote!(
+        impl #impl_generics ::zeroable::IsZero for #name #ty_generics
+        #where_clause_tokens
+        {
+            fn is_zero(&self) -> bool {
+                match *self {
+                    #zero_arm
+                    #other_arms
+                }
+            }
+        }
+    );
+
+    match config.debug_print() {
+        Some(mode) => debug_print(ds, "IsZero", mode, tokens),
+        None => Ok(tokens),
+    }
+}
+
+/// Emits the match arm for the zero variant,
+/// which checks that every field of the variant is zero.
+fn emit_zero_arm(ds: &'_ DataStructure<'_>, variant: &'_ Struct<'_>) -> TokenStream2 {
+    let name = ds.name;
+
+    let path = match ds.data_variant {
+        DataVariant::Enum => {
+            let variant_name = variant.name;
+            quote!(#name::#variant_name)
+        }
+        _ => quote!(#name),
+    };
+
+    let field_idents = variant.fields.iter().map(|f| &f.ident);
+    let field_names = variant.fields.iter().map(|f| f.ident()).collect::<Vec<_>>();
+    let field_checks = variant
+        .fields
+        .iter()
+        .zip(&field_names)
+        .map(|(field, fname)| {
+            let ty = field.ty;
+            quote_spanned!(field.ty_span()=>
+                && <#ty as ::zeroable::IsZero>::is_zero(#fname)
+            )
+        });
+
+    quote!(
+        #path{ #( #field_idents: ref #field_names, )* .. } => {
+            true #( #field_checks )*
+        }
+    )
+}
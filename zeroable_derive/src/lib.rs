@@ -9,10 +9,9 @@ extern crate proc_macro;
 #[macro_use]
 mod macros;
 
-mod attribute_parsing_shared;
-mod datastructure;
-mod repr_attr;
-mod utils;
+mod debug_print;
+mod is_zero_macro;
+mod reset_macro;
 mod zeroable_macro;
 
 use proc_macro::TokenStream as TokenStream1;
@@ -20,11 +19,25 @@ use proc_macro2::TokenStream as TokenStream2;
 
 /// This macro is documented in [`zeroable::zeroable_docs`](./zeroable_docs/index.html).
 /// (the link only works in the `zeroable` crate)
-#[proc_macro_derive(Zeroable, attributes(zero))]
+#[proc_macro_derive(Zeroable, attributes(zero, zeroable))]
 pub fn derive_zeroable(input: TokenStream1) -> TokenStream1 {
     parse_or_compile_err(input, zeroable_macro::derive).into()
 }
 
+/// This macro is documented in [`zeroable::Reset`](./reset/trait.Reset.html).
+/// (the link only works in the `zeroable` crate)
+#[proc_macro_derive(Reset, attributes(zero))]
+pub fn derive_reset(input: TokenStream1) -> TokenStream1 {
+    parse_or_compile_err(input, reset_macro::derive).into()
+}
+
+/// This macro is documented in [`zeroable::IsZero`](./is_zero/trait.IsZero.html).
+/// (the link only works in the `zeroable` crate)
+#[proc_macro_derive(IsZero, attributes(zero))]
+pub fn derive_is_zero(input: TokenStream1) -> TokenStream1 {
+    parse_or_compile_err(input, is_zero_macro::derive).into()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 fn parse_or_compile_err<P, F>(input: TokenStream1, f: F) -> TokenStream2
@@ -1,6 +1,6 @@
 macro_rules! spanned_err {
     ( $e:expr, $($fmt:tt)* ) => ({
-        crate::utils::spanned_err(
+        ::zeroable_derive_internals::utils::spanned_err(
             &$e,
             &format!($($fmt)*),
         )
@@ -9,7 +9,7 @@ macro_rules! spanned_err {
 
 macro_rules! return_spanned_err {
     ( $e:expr, $($fmt:tt)* ) => ({
-        return Err(crate::utils::spanned_err(
+        return Err(::zeroable_derive_internals::utils::spanned_err(
             &$e,
             &format!($($fmt)*),
         ))
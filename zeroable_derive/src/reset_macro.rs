@@ -0,0 +1,103 @@
+use crate::{
+    debug_print::debug_print,
+    zeroable_macro::{
+        attribute_parsing::{self, ZeroConfig},
+        zeroable_where_clause,
+    },
+};
+
+use zeroable_derive_internals::datastructure::{DataStructure, DataVariant, Struct};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, quote_spanned};
+
+use syn::DeriveInput;
+
+pub fn derive(data: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let ds = &DataStructure::new(&data);
+
+    let config = &attribute_parsing::parse_attrs_for_zeroed(ds)?;
+
+    if ds.data_variant == DataVariant::Union {
+        return_spanned_err! { ds.name,"Reset cannot be derived for unions." }
+    }
+
+    let name = ds.name;
+
+    let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+
+    // The fields that are zeroed by `reset`.
+    let zeroed_fields = ds
+        .variants
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .filter(|field| !config.kept_fields().contains(&field.index))
+        .collect::<Vec<_>>();
+
+    let where_clause_tokens = zeroable_where_clause(ds, config, &zeroed_fields);
+
+    let match_arms = ds
+        .variants
+        .iter()
+        .map(|variant| emit_match_arm(ds, config, variant));
+
+    let tokens = quote!(
+        impl #impl_generics ::zeroable::Reset for #name #ty_generics
+        #where_clause_tokens
+        {
+            fn reset(&mut self){
+                match *self {
+                    #( #match_arms )*
+                }
+            }
+        }
+    );
+
+    match config.debug_print() {
+        Some(mode) => debug_print(ds, "Reset", mode, tokens),
+        None => Ok(tokens),
+    }
+}
+
+/// Emits the match arm that zeroes the fields of `variant`
+/// that don't have a `#[zero(keep)]` attribute.
+fn emit_match_arm(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    variant: &'_ Struct<'_>,
+) -> TokenStream2 {
+    let name = ds.name;
+
+    let path = match ds.data_variant {
+        DataVariant::Enum => {
+            let variant_name = variant.name;
+            quote!(#name::#variant_name)
+        }
+        _ => quote!(#name),
+    };
+
+    let zeroed_fields = variant
+        .fields
+        .iter()
+        .filter(|f| !config.kept_fields().contains(&f.index))
+        .collect::<Vec<_>>();
+
+    let field_idents = zeroed_fields.iter().map(|f| &f.ident);
+    let field_names = zeroed_fields.iter().map(|f| f.ident()).collect::<Vec<_>>();
+    let zero_fields = zeroed_fields
+        .iter()
+        .zip(&field_names)
+        .map(|(field, fname)| {
+            let ty = field.ty;
+            quote_spanned!(field.ty_span()=>
+                ::zeroable::zero_in_place::<#ty>(#fname);
+            )
+        });
+
+    quote!(
+        #path{ #( #field_idents: ref mut #field_names, )* .. } => {
+            #( #zero_fields )*
+        }
+    )
+}
@@ -1,7 +1,10 @@
-use crate::{
+use crate::debug_print::debug_print;
+
+use zeroable_derive_internals::{
     datastructure::{DataStructure, DataVariant, MyField},
     repr_attr::ReprAttr,
-    utils::ExprExt,
+    type_param_usage::TypeParamUsage,
+    zero_variant::zero_variant_index,
 };
 
 use proc_macro2::TokenStream as TokenStream2;
@@ -10,11 +13,18 @@ use quote::{quote, quote_spanned};
 
 use syn::{punctuated::Punctuated, DeriveInput};
 
-mod attribute_parsing;
+mod c_header;
+mod layout_info;
+mod new_zeroed;
+mod remote;
+mod warnings;
+mod with_rest;
 
 #[cfg(test)]
 mod tests;
 
+pub(crate) use zeroable_derive_internals::attribute_parsing;
+
 use self::attribute_parsing::{IsBounded, IsZeroable, ZeroConfig};
 
 pub fn derive(ref data: DeriveInput) -> Result<TokenStream2, syn::Error> {
@@ -22,12 +32,32 @@ pub fn derive(ref data: DeriveInput) -> Result<TokenStream2, syn::Error> {
 
     let config = &attribute_parsing::parse_attrs_for_zeroed(ds)?;
 
-    let field_asserts = match ds.data_variant {
-        DataVariant::Struct => emit_field_assertions(&ds.variants[0].fields),
-        DataVariant::Enum => checks_and_emit_enum_field_assertions(ds, config)?,
-        DataVariant::Union => checks_and_emit_union_field_assertions(ds, config)?,
+    let with_rest_fields = if config.with_rest() {
+        Some(with_rest::split_fields(ds, config))
+    } else {
+        None
     };
 
+    let mut zero_variant = None;
+
+    // The fields that are required to be zeroable.
+    let zeroed_fields = match ds.data_variant {
+        DataVariant::Struct => match &with_rest_fields {
+            Some((zeroed_fields, _)) => zeroed_fields.clone(),
+            None => ds.variants[0].fields.iter().collect(),
+        },
+        DataVariant::Enum => {
+            let variant_index = zero_variant_index(ds, config.repr_attr())?;
+            zero_variant = Some(variant_index);
+            ds.variants[variant_index].fields.iter().collect()
+        }
+        DataVariant::Union => union_zeroable_fields(ds, config)?,
+    };
+
+    let field_asserts = emit_field_assertions(zeroed_fields.iter().cloned());
+
+    let warnings = warnings::emit_warnings(ds, config, &zeroed_fields);
+
     let zeroable_docs = match ds.data_variant {
         _ if !ds.is_public() => String::new(),
         DataVariant::Struct => String::new(),
@@ -37,32 +67,32 @@ pub fn derive(ref data: DeriveInput) -> Result<TokenStream2, syn::Error> {
 
     let name = ds.name;
 
-    let mut unbounded_tp = config.unbounded_typarams.iter().cloned();
-    let ty_params = ds
-        .generics
-        .type_params()
-        .map(|x| &x.ident)
-        .filter(move |_| unbounded_tp.next() != Some(IsBounded::No));
+    let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
 
-    let extra_predicates = &*config.extra_predicates;
+    let where_clause_tokens = zeroable_where_clause(ds, config, &zeroed_fields);
 
-    let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+    let test_code = config.test_code();
 
-    let empty_preds = Punctuated::new();
-
-    let where_preds = where_clause
-        .as_ref()
-        .map_or(&empty_preds, |x| &x.predicates)
-        .into_iter();
-
-    let where_clause_tokens = quote!(
-        where
-            #( #where_preds ,)*
-            #( #ty_params: ::zeroable::Zeroable, )*
-            #( #extra_predicates ,)*
-    );
+    // Structs with `#[zero(nonzero)]` fields are not zeroable,
+    // those fields have to be passed to the `zeroed_with` constructor.
+    let (zeroable_impl, zeroed_with) = match &with_rest_fields {
+        Some((zeroed_fields, param_fields)) => (
+            param_fields.is_empty(),
+            with_rest::emit_zeroed_with(ds, config, zeroed_fields, param_fields),
+        ),
+        None => (true, TokenStream2::new()),
+    };
 
-    let test_code = &*config.test_code;
+    let zeroable_impl = if zeroable_impl {
+        quote!(
+            #[doc=#zeroable_docs]
+            unsafe impl #impl_generics ::zeroable::Zeroable for #name #ty_generics
+            #where_clause_tokens
+            {}
+        )
+    } else {
+        TokenStream2::new()
+    };
 
     let tokens = quote!(
         #[doc(hidden)]
@@ -73,67 +103,229 @@ pub fn derive(ref data: DeriveInput) -> Result<TokenStream2, syn::Error> {
                 #({ #test_code })*
                 #field_asserts
             };
+
+            #warnings
         }
 
-        #[doc=#zeroable_docs]
-        unsafe impl #impl_generics ::zeroable::Zeroable for #name #ty_generics
-        #where_clause_tokens
-        {}
+        #zeroable_impl
+
+        #zeroed_with
+    );
+
+    let variant_info = match zero_variant {
+        Some(zero_variant) if config.variant_info() => emit_variant_info(ds, zero_variant),
+        _ => TokenStream2::new(),
+    };
+
+    let layout_info = if config.layout_info() {
+        layout_info::emit_layout_impl(ds, config)
+    } else {
+        TokenStream2::new()
+    };
+
+    let c_header = if config.c_header() {
+        c_header::emit_c_header_impls(ds)
+    } else {
+        TokenStream2::new()
+    };
+
+    let new_zeroed = if config.size_field().is_some() || !config.init_fields().is_empty() {
+        new_zeroed::emit_new_zeroed(ds, config, &where_clause_tokens)
+    } else {
+        TokenStream2::new()
+    };
+
+    let repr_c_impl = match config.repr_attr() {
+        ReprAttr::C { .. } | ReprAttr::IntegerRepr => {
+            let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+            let where_clause = repr_c_where_clause(ds);
+            quote!(
+                unsafe impl #impl_generics ::zeroable::ffi::ReprC for #name #ty_generics
+                #where_clause
+                {}
+            )
+        }
+        ReprAttr::Transparent | ReprAttr::Rust => TokenStream2::new(),
+    };
+
+    let remote_tokens = match config.remote() {
+        Some(remote) => remote::emit_remote_impl(ds, config, remote)?,
+        None => TokenStream2::new(),
+    };
+
+    let tokens = quote!(
+        #tokens #variant_info #layout_info #new_zeroed #repr_c_impl #c_header #remote_tokens
     );
 
-    if config.debug_print {
-        panic!("\n\n\n{}\n\n\n", tokens);
+    match config.debug_print() {
+        Some(mode) => debug_print(ds, "Zeroable", mode, tokens),
+        None => Ok(tokens),
     }
+}
 
-    Ok(tokens)
+/// Outputs the where clause of the type definition,
+/// with the `Zeroable` bounds for type parameters and
+/// the predicates from `#[zero(bound="...")]` attributes.
+///
+/// `zeroed_fields` are the fields whose `#[zero(bound="...")]` predicates are included.
+pub(crate) fn zeroable_where_clause(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    zeroed_fields: &[&MyField<'_>],
+) -> TokenStream2 {
+    bounded_where_clause(ds, config, zeroed_fields, &quote!(::zeroable::Zeroable))
+}
+
+/// Outputs the where clause of the type definition,
+/// with `bound` as the bound for type parameters,
+/// the predicates from type-level `#[zero(bound="...")]` attributes,
+/// and the ones from field-level `#[zero(bound="...")]` attributes on `zeroed_fields`.
+///
+/// With the `#[zeroable(bound="...")]` attribute,
+/// the types of the `zeroed_fields` that use type parameters are bounded by `bound`,
+/// instead of the type parameters.
+pub(crate) fn bounded_where_clause(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    zeroed_fields: &[&MyField<'_>],
+    bound: &TokenStream2,
+) -> TokenStream2 {
+    bounded_where_clause_for(ds, config, zeroed_fields, bound, |_| true)
 }
 
-fn checks_and_emit_enum_field_assertions(
+/// Outputs the same where clause as `bounded_where_clause`,
+/// except that only the type parameters at the positions for which `is_bounded` returns true
+/// are bounded by `bound`.
+pub(crate) fn bounded_where_clause_for<F>(
     ds: &'_ DataStructure<'_>,
     config: &'_ ZeroConfig<'_>,
-) -> Result<TokenStream2, syn::Error> {
-    assert_eq!(ds.data_variant, DataVariant::Enum);
+    zeroed_fields: &[&MyField<'_>],
+    bound: &TokenStream2,
+    is_bounded: F,
+) -> TokenStream2
+where
+    F: Fn(usize) -> bool,
+{
+    let mut unbounded_tp = config.unbounded_typarams().iter().cloned();
+    let ty_params = ds
+        .generics
+        .type_params()
+        .enumerate()
+        .filter(move |_| unbounded_tp.next() != Some(IsBounded::No))
+        .filter(|_| !config.no_auto_bounds())
+        .filter(|(pos, _)| is_bounded(*pos))
+        .map(|(_, x)| &x.ident);
 
-    const REPR_ERR: &str = "Expected a `#[repr(C/<integer_type>/Transparent)]`enum";
+    let extra_predicates = config.extra_predicates();
 
-    const ENUM_DISCR_ERR: &str = "
-Expected either:
+    let field_predicates = config
+        .field_predicates()
+        .iter()
+        .filter(|(index, _)| zeroed_fields.iter().any(|field| field.index == *index))
+        .map(|(_, pred)| pred);
+
+    let field_types = if config.field_type_bounds() {
+        let usage = TypeParamUsage::new(ds);
+        zeroed_fields
+            .iter()
+            .filter(|field| usage.used_by(field.index).next().is_some())
+            .map(|field| field.ty)
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
 
-- The first variant to have an implicit discriminant,
+    let empty_preds = Punctuated::new();
 
-- Any variant with an explicit `0` discriminant,
-    ";
+    let where_preds = ds
+        .generics
+        .where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .into_iter();
 
-    if ds.variants.len() == 0 {
-        return_spanned_err! { ds.name,"Zero variant enums cannot implement Zeroable." }
-    }
+    quote!(
+        where
+            #( #where_preds ,)*
+            #( #ty_params: #bound, )*
+            #( #extra_predicates ,)*
+            #( #field_predicates ,)*
+            #( #field_types: #bound, )*
+    )
+}
 
-    let zero_variant = match config.repr_attr {
-        ReprAttr::C { .. } | ReprAttr::IntegerRepr { .. } => {
-            if ds.variants[0].discriminant.map_or(true, ExprExt::is_zero) {
-                0
-            } else {
-                let offset = 1;
-                ds.variants[offset..]
-                    .iter()
-                    .position(|var| var.discriminant.map_or(false, ExprExt::is_zero))
-                    .ok_or_else(move || spanned_err! { ds.name,"{}",ENUM_DISCR_ERR })?
-                    + offset
+/// The where clause of the `ReprC` impl,
+/// which requires the types of the fields of every variant to implement `ReprC`.
+///
+/// The bounds are higher-ranked so that a field with a concrete type that doesn't implement
+/// `ReprC` makes the impl not apply,instead of causing a compile-time error.
+fn repr_c_where_clause(ds: &'_ DataStructure<'_>) -> TokenStream2 {
+    let empty_preds = Punctuated::new();
+
+    let where_preds = ds
+        .generics
+        .where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .into_iter();
+
+    let field_types = ds
+        .variants
+        .iter()
+        .flat_map(|vari| &vari.fields)
+        .map(|field| field.ty);
+
+    quote!(
+        where
+            #( #where_preds ,)*
+            #( for<'__zeroable> #field_types: ::zeroable::ffi::ReprC, )*
+    )
+}
+
+/// Emits the `ZERO_VARIANT_NAME` associated constant and the `is_zero_variant` method,
+/// for the `#[zero(variant_info)]` attribute.
+fn emit_variant_info(ds: &'_ DataStructure<'_>, zero_variant: usize) -> TokenStream2 {
+    let name = ds.name;
+    let vis = ds.vis;
+    let variant_name = ds.variants[zero_variant].name;
+    let variant_str = variant_name.to_string();
+
+    let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let const_docs = format!(
+        "The name of the variant that `Zeroable::zeroed` returns,`{}`.",
+        variant_str,
+    );
+    let method_docs = format!(
+        "Whether `self` is the `{}` variant,which is the one that `Zeroable::zeroed` returns.\n\n\
+         This ignores the values of the fields.",
+        variant_str,
+    );
+
+    quote!(
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc=#const_docs]
+            #vis const ZERO_VARIANT_NAME: &'static str = #variant_str;
+
+            #[doc=#method_docs]
+            #[inline]
+            #vis fn is_zero_variant(&self) -> bool {
+                match *self {
+                    #name::#variant_name{..} => true,
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
             }
         }
-        ReprAttr::Transparent => 0,
-        ReprAttr::Rust => {
-            return_spanned_err! { ds.name,"{}",REPR_ERR }
-        }
-    };
-
-    Ok(emit_field_assertions(&ds.variants[zero_variant].fields))
+    )
 }
 
-fn checks_and_emit_union_field_assertions(
-    ds: &'_ DataStructure<'_>,
+/// Gets the fields of a union that are required to be zeroable,
+/// erroring if there are none.
+fn union_zeroable_fields<'a>(
+    ds: &'a DataStructure<'a>,
     config: &'_ ZeroConfig<'_>,
-) -> Result<TokenStream2, syn::Error> {
+) -> Result<Vec<&'a MyField<'a>>, syn::Error> {
     assert_eq!(ds.data_variant, DataVariant::Union);
 
     const EXPECTED_NONZERO: &str =
@@ -147,11 +339,11 @@ fn checks_and_emit_union_field_assertions(
     if union_.fields.len() == 0 {
         return_spanned_err! { ds.name,"Zero fields union cannot implement Zeroable." }
     } else {
-        let zeroable_fields = if config.repr_attr == ReprAttr::Transparent {
+        let zeroable_fields = if config.repr_attr() == ReprAttr::Transparent {
             vec![&union_.fields[0]]
         } else {
             config
-                .zeroable_fields
+                .zeroable_fields()
                 .iter()
                 .cloned()
                 .zip(&union_.fields)
@@ -162,13 +354,13 @@ fn checks_and_emit_union_field_assertions(
                 .collect::<Vec<_>>()
         };
 
-        match (zeroable_fields.is_empty(), config.default_zeroab) {
+        match (zeroable_fields.is_empty(), config.default_zeroab()) {
             (true, IsZeroable::Yes) => return_spanned_err!(ds.name, "{}", EXPECTED_NONZERO),
             (true, IsZeroable::No) => return_spanned_err!(ds.name, "{}", EXPECTED_ZEROABLE),
             (false, _) => {}
         }
 
-        Ok(emit_field_assertions(zeroable_fields))
+        Ok(zeroable_fields)
     }
 }
 
@@ -202,7 +394,7 @@ fn docs_for_union(ds: &'_ DataStructure<'_>, config: &'_ ZeroConfig<'_>) -> Stri
     let mut nonzero_fields = Vec::new();
 
     config
-        .zeroable_fields
+        .zeroable_fields()
         .iter()
         .zip(&union_.fields)
         .filter(|(_, f)| f.is_public())
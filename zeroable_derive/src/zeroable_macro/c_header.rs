@@ -0,0 +1,68 @@
+use zeroable_derive_internals::datastructure::{DataStructure, DataVariant};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+/// Emits the `CType` and `CHeaderType` impls,for the `#[zero(c_header)]` attribute.
+pub(super) fn emit_c_header_impls(ds: &'_ DataStructure<'_>) -> TokenStream2 {
+    let name = ds.name;
+    let c_name = c_identifier(&name.to_string());
+
+    let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let kind = match ds.data_variant {
+        DataVariant::Struct => quote!(Struct),
+        DataVariant::Union => quote!(Union),
+        DataVariant::Enum => unreachable!("`#[zero(c_header)]` is rejected on enums"),
+    };
+
+    let fields = ds.variants[0].fields.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = field.ty;
+        let c_name = c_identifier(&field.ident.to_string());
+
+        quote!(
+            ::zeroable::c_header::CField {
+                name: #c_name,
+                ty: <#ty as ::zeroable::c_header::CType>::C_TYPE,
+                offset: ::core::mem::offset_of!(Self, #ident),
+                align: ::core::mem::align_of::<#ty>(),
+            }
+        )
+    });
+
+    quote!(
+        unsafe impl #impl_generics ::zeroable::c_header::CType for #name #ty_generics
+        #where_clause
+        {
+            const C_TYPE: ::zeroable::c_header::CTypeDesc =
+                ::zeroable::c_header::CTypeDesc::Named(#c_name);
+        }
+
+        impl #impl_generics ::zeroable::c_header::CHeaderType for #name #ty_generics
+        #where_clause
+        {
+            const C_DEFINITION: ::zeroable::c_header::CDefinition =
+                ::zeroable::c_header::CDefinition {
+                    name: #c_name,
+                    kind: ::zeroable::c_header::CKind::#kind,
+                    size: ::core::mem::size_of::<Self>(),
+                    align: ::core::mem::align_of::<Self>(),
+                    fields: &[ #( #fields, )* ],
+                };
+        }
+    )
+}
+
+/// Converts a Rust identifier to a C identifier,
+/// removing the `r#` prefix of raw identifiers,
+/// and prefixing the position of tuple fields with `_`.
+fn c_identifier(ident: &str) -> String {
+    let ident = ident.trim_start_matches("r#");
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else {
+        ident.to_string()
+    }
+}
@@ -0,0 +1,60 @@
+use zeroable_derive_internals::{
+    datastructure::{DataStructure, DataVariant},
+    repr_attr::ReprAttr,
+};
+
+use super::attribute_parsing::{IsZeroable, ZeroConfig};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, ToTokens};
+
+/// Emits the `ZeroableLayout` impl,for the `#[zero(layout_info)]` attribute.
+pub(super) fn emit_layout_impl(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+) -> TokenStream2 {
+    assert_ne!(ds.data_variant, DataVariant::Enum);
+
+    let name = ds.name;
+
+    let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let fields = &ds.variants[0].fields;
+
+    let field_layouts = fields.iter().enumerate().map(|(i, field)| {
+        let ident = &field.ident;
+        let ty = field.ty;
+        let field_name = field.ident.to_string();
+        let type_name = ty.to_token_stream().to_string();
+
+        // Transparent unions only require the first field to be zeroable.
+        let zeroable = if ds.data_variant == DataVariant::Union
+            && config.repr_attr() == ReprAttr::Transparent
+        {
+            i == 0
+        } else {
+            config.zeroable_fields().get(i) != Some(&IsZeroable::No)
+        };
+
+        quote!(
+            ::zeroable::layout::FieldLayout {
+                name: #field_name,
+                type_name: #type_name,
+                offset: ::core::mem::offset_of!(Self, #ident),
+                size: ::core::mem::size_of::<#ty>(),
+                zeroable: #zeroable,
+            }
+        )
+    });
+
+    quote!(
+        impl #impl_generics ::zeroable::layout::ZeroableLayout for #name #ty_generics
+        #where_clause
+        {
+            const ZEROABLE_LAYOUT: &'static [::zeroable::layout::FieldLayout] = &[
+                #( #field_layouts, )*
+            ];
+        }
+    )
+}
@@ -0,0 +1,64 @@
+use zeroable_derive_internals::datastructure::DataStructure;
+
+use super::attribute_parsing::ZeroConfig;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, quote_spanned, ToTokens};
+
+/// Emits the `new_zeroed` constructor,
+/// for the `#[zero(size_field="...")]` and `#[zero(init(...))]` attributes.
+pub(super) fn emit_new_zeroed(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    where_clause: &TokenStream2,
+) -> TokenStream2 {
+    let fields = &ds.variants[0].fields;
+    let name = ds.name;
+    let vis = ds.vis;
+
+    let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+
+    let mut doc = format!("Constructs a zeroed `{}`,with these fields set:\n\n", name);
+
+    let size_assignment = config.size_field().map(|index| {
+        let field = &fields[index.pos];
+        let ident = &field.ident;
+        let ty = field.ty;
+        doc.push_str(&format!("- `{}`: the size of `{}`\n\n", ident, name));
+        quote_spanned!(field.ty_span()=>
+            this.#ident = <#ty as ::zeroable::ffi::SizeField>::from_size(
+                ::core::mem::size_of::<Self>()
+            );
+        )
+    });
+
+    let init_assignments = config
+        .init_fields()
+        .iter()
+        .map(|(index, expr)| {
+            let ident = &fields[index.pos].ident;
+            doc.push_str(&format!("- `{}`: `{}`\n\n", ident, expr.to_token_stream()));
+            quote!( this.#ident = #expr; )
+        })
+        .collect::<Vec<_>>();
+
+    if config.size_field().is_some() {
+        doc.push_str("# Panics\n\nPanics if the size of the type doesn't fit in the size field.");
+    }
+
+    quote!(
+        impl #impl_generics #name #ty_generics
+        #where_clause
+        {
+            #[doc=#doc]
+            #[inline]
+            #vis fn new_zeroed() -> Self {
+                let mut this = <Self as ::zeroable::Zeroable>::zeroed();
+                #size_assignment
+                #( #init_assignments )*
+                this
+            }
+        }
+    )
+}
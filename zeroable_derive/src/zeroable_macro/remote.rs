@@ -0,0 +1,142 @@
+use zeroable_derive_internals::datastructure::{DataStructure, DataVariant};
+
+use super::attribute_parsing::ZeroConfig;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, quote_spanned};
+
+use syn::Path;
+
+/// Emits the layout assertions between the mirror type and the remote type,
+/// and either a `Zeroable` impl for the remote type,
+/// or a `#[repr(transparent)]` newtype wrapping it which implements `Zeroable`.
+pub(super) fn emit_remote_impl(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    remote: &Path,
+) -> Result<TokenStream2, syn::Error> {
+    if ds.data_variant == DataVariant::Enum {
+        return_spanned_err! {
+            ds.name,
+            "The `#[zero(remote=\"...\")]` attribute can only be used on structs and unions",
+        }
+    }
+
+    if !ds.generics.params.is_empty() {
+        return_spanned_err! {
+            ds.generics,
+            "The `#[zero(remote=\"...\")]` attribute can't be used on generic types",
+        }
+    }
+
+    let name = ds.name;
+    let remote_str = path_to_string(remote);
+    let fields = &ds.variants[0].fields;
+
+    let size_msg = format!(
+        "The size of `{}` doesn't match the size of its mirror `{}`",
+        remote_str, name,
+    );
+    let align_msg = format!(
+        "The alignment of `{}` doesn't match the alignment of its mirror `{}`",
+        remote_str, name,
+    );
+
+    let offset_asserts = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let msg = format!(
+            "The offset of the `{}` field differs between `{}` and its mirror `{}`",
+            field.ident, remote_str, name,
+        );
+        quote!(
+            assert!(
+                ::core::mem::offset_of!(#name, #ident) ==
+                ::core::mem::offset_of!(#remote, #ident),
+                #msg
+            );
+        )
+    });
+
+    let this = quote!(this);
+
+    // Coercing a reference to a raw pointer doesn't go through `Deref`,
+    // so this requires the field types to be exactly the same.
+    let field_type_checks = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = field.ty;
+        quote_spanned!(field.ty_span()=>
+            let _: *const #ty = &#this.#ident;
+        )
+    });
+
+    let field_checks = match ds.data_variant {
+        DataVariant::Union => quote!(
+            unsafe{ #( #field_type_checks )* }
+        ),
+        _ => {
+            let field_names = fields.iter().map(|f| &f.ident);
+            quote!(
+                let #remote { #( #field_names: _, )* } = this;
+                #( #field_type_checks )*
+            )
+        }
+    };
+
+    let impl_ = match config.remote_newtype() {
+        Some(newtype) => {
+            let vis = ds.vis;
+            let doc = format!(
+                "A `#[repr(transparent)]` wrapper around `{}` which implements `Zeroable`.",
+                remote_str,
+            );
+            quote!(
+                #[doc=#doc]
+                #[repr(transparent)]
+                #vis struct #newtype(pub #remote);
+
+                unsafe impl ::zeroable::Zeroable for #newtype {}
+            )
+        }
+        None => quote!(
+            unsafe impl ::zeroable::Zeroable for #remote {}
+        ),
+    };
+
+    Ok(quote!(
+        const _:()={
+            assert!(
+                ::core::mem::size_of::<#name>() == ::core::mem::size_of::<#remote>(),
+                #size_msg
+            );
+            assert!(
+                ::core::mem::align_of::<#name>() == ::core::mem::align_of::<#remote>(),
+                #align_msg
+            );
+            #( #offset_asserts )*
+        };
+
+        const _:()={
+            #[allow(dead_code)]
+            fn remote_fields_check(this: &#remote){
+                #field_checks
+            }
+        };
+
+        #impl_
+    ))
+}
+
+fn path_to_string(path: &Path) -> String {
+    let mut buffer = String::new();
+    if path.leading_colon.is_some() {
+        buffer.push_str("::");
+    }
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i != 0 {
+            buffer.push_str("::");
+        }
+        buffer.push_str(&segment.ident.to_string());
+    }
+    buffer
+}
@@ -1,5 +1,7 @@
 use super::derive;
 
+use crate::debug_print::expansion_dir;
+
 use regex::Regex;
 
 fn derive_from_str(s: &str) -> Result<String, String> {
@@ -176,6 +178,20 @@ fn test_compilation() {
             has_errors: true,
             expected: r#"Cannot.*use.*attribute.*enum"#.into(),
         });
+        testcases.push(TestCase {
+            code: "
+                #[zero(nonzero_fields)]
+                #[zero(with_rest)]
+                pub struct Struct {
+                    pub left: Box<u8>,
+                    #[zero(zeroable)]
+                    pub right: u8,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"fn zeroed_with *\( *left *: *Box *< *u8 *> *, *\)"#.into(),
+        });
 
         testcases.push(TestCase {
             code: struct_with_attr("", "#[zero(nonzero)]"),
@@ -199,6 +215,469 @@ fn test_compilation() {
             expected: r#"Cannot.*use.*\(zeroable\).*attribute.*enum"#.into(),
         });
 
+        testcases.push(TestCase {
+            code: "
+                #[zero(remote=\"ffi::Enum\")]
+                #[repr(u8)]
+                enum Enum{
+                    A,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"remote.*only.*structs.*unions"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(remote=\"ffi::Generic\")]
+                struct Generic<T>{
+                    a:T,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"remote.*can't.*generic"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(remote_newtype=\"Wrapper\")]
+                struct Hello{}
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"remote_newtype.*requires.*remote"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(remote=\"ffi::Hello\",remote_newtype=\"Wrapper\")]
+                struct Hello{
+                    a:u32,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"offset_of.*Hello *, *a.*struct Wrapper *\( *pub ffi *:: *Hello *\)"#
+                .into(),
+        });
+
+        testcases.push(TestCase {
+            code: "
+                #[zero(with_rest)]
+                #[repr(u8)]
+                enum Enum{
+                    A,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"Cannot.*use.*\(with_rest\).*attribute.*enum"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(with_rest)]
+                union Union{
+                    a:u8,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"Cannot.*use.*\(with_rest\).*attribute.*union"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(with_rest)]
+                struct Hello{
+                    #[zero(nonzero)]
+                    a:Box<u32>,
+                    b:u32,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"fn zeroed_with *\( *a *: *Box *< *u32 *> *, *\)[^Z]*b *: *< *u32 *as *:: *zeroable *:: *Zeroable *> *:: *zeroed"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(with_rest)]
+                struct Hello<T, U>{
+                    #[zero(nonzero)]
+                    a:Box<[T]>,
+                    b:U,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"impl *< *T *, *U *> *Hello *< *T *, *U *> *where *U *: *:: *zeroable *:: *Zeroable *, *\{[^}]*fn zeroed_with"#.into(),
+        });
+
+        testcases.push(TestCase {
+            code: "
+                #[zero(variant_info)]
+                struct Hello{
+                    a:u8,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"Cannot.*use.*\(variant_info\).*attribute.*struct"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(variant_info)]
+                #[repr(i8)]
+                pub enum Ordering{
+                    Less=-1,
+                    Equal=0,
+                    Greater=1,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"pub const ZERO_VARIANT_NAME *: *& *'static str *= *"Equal".*pub fn is_zero_variant.*Ordering *:: *Equal *\{ *\.\. *\} *=> *true"#.into(),
+        });
+
+        testcases.push(TestCase {
+            code: "
+                #[zero(layout_info)]
+                #[repr(u8)]
+                enum Enum{
+                    A,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"Cannot.*use.*\(layout_info\).*attribute.*enum"#.into(),
+        });
+
+        testcases.push(TestCase {
+            code: "
+                #[zero(debug_print)]
+                struct Hello{
+                    a:u8,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"unsafe impl *:: *zeroable *:: *Zeroable *for *Hello.*compile_error.*derive output for `Hello`:\\n\\n.*unsafe impl ::zeroable::Zeroable for Hello \{\}"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(debug_print=\"stdout\")]
+                struct Hello{
+                    a:u8,
+                }
+            "
+            .to_string(),
+            has_errors: true,
+            expected: r#"Expected either.*debug_print.*error.*debug_print.*file"#.into(),
+        });
+
+        testcases.push(TestCase {
+            code: "#[zero(nonzero_field)] union U{ a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Unrecognized attribute.*nonzero_field.*Did you mean `nonzero_fields`\?.*can be used on the type are.*not_zeroable"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bounds=\"T:Copy\")] struct S<T>{ a:T }".to_string(),
+            has_errors: true,
+            expected: r#"Unrecognized.*bounds.*Did you mean `bound`\?"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "union U{ #[zero(non_zero)] a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Unrecognized.*non_zero.*Did you mean `nonzero`\?.*fields are.*zeroable.*nonzero.*keep"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bound)] struct S<T>{ a:T }".to_string(),
+            has_errors: true,
+            expected: r#"Unrecognized.*`#\[zero\(bound\)\]`.*Expected it to be written as `#\[zero\(bound=[^`]*\.\.\.[^`]*\)\]` or `#\[zero\(bound\(\.\.\.\)\)\]`\\n"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(keep)] struct S{ a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Unrecognized.*keep.*can only be used on fields"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(u8)] enum E{ #[zero(keep)] A }".to_string(),
+            has_errors: true,
+            expected:
+                r#"Unrecognized.*keep.*no `#\[zero\(\.\.\.\)\]` attributes for enum variants"#
+                    .into(),
+        });
+        testcases.push(TestCase {
+            code: "union U{ #[zero(zeroable)] #[zero(nonzero)] a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"`#\[zero\(zeroable\)\]` and `#\[zero\(nonzero\)\]`.*can.t be used together on the same field"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "union U{ #[zero(nonzero, zeroable)] a:u8, b:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"`#\[zero\(nonzero\)\]` and `#\[zero\(zeroable\)\]`.*same field"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(layout_info)] #[zero(layout_info)] struct S{ a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Duplicate `#\[zero\(layout_info\)\]` attribute"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "struct S{ #[zero(keep, keep)] a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Duplicate `#\[zero\(keep\)\]` attribute on this field"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(debug_print, debug_print=\"file\")] struct S{ a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"Duplicate `#\[zero\(debug_print\)\]`"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(not_zeroable(T))] #[zero(not_zeroable(U, T))] struct S<T,U>{ a:T, b:U }"
+                .to_string(),
+            has_errors: true,
+            expected: r#"`T` type parameter is listed more than once"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                union U{
+                    #[zero(nonzero)]
+                    a:u8,
+                    #[zero(zeroable)]
+                    b:u8,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"GetAssertZeroable"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(not_zeroable(T))] struct S<T>{ a:[T;2] }".to_string(),
+            has_errors: false,
+            expected: r#"deprecated *\( *note *= *"The `T` type parameter is required to be zeroable by the `a` field.*const ineffective_not_zeroable"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bound=\"T: ::zeroable::Zeroable\")] struct S<T>{ a:T }".to_string(),
+            has_errors: false,
+            expected: r#"deprecated.*The `T: Zeroable` bound is redundant.*const redundant_bound"#
+                .into(),
+        });
+        testcases.push(TestCase {
+            code: "union U{ #[zero(nonzero)] a:u32, b:u8 }".to_string(),
+            has_errors: false,
+            expected: r#"redundant_nonzero *\( *\)"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(allow(redundant_nonzeros))] struct S{ a:u8 }".to_string(),
+            has_errors: true,
+            expected:
+                r#"Unrecognized warning.*redundant_nonzeros.*Did you mean `redundant_nonzero`"#
+                    .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bound(T: Copy, U::Item: Clone))] struct S<T,U: Iterator>{ a:T, b:U }"
+                .to_string(),
+            has_errors: false,
+            expected: r#"Zeroable *, *T *: *Copy *, *U *:: *Item *: *Clone *, *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bound=\"\")] struct S<T>{ a:PhantomData<T> }".to_string(),
+            has_errors: false,
+            expected: r#"S *< *T *> *where *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[repr(u8)]
+                enum E<T,U>{
+                    A(#[zero(bound=\"T: Copy\")] T),
+                    B(#[zero(bound(U: Copy))] U),
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"Zeroable *, *T *: *Copy *, *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[zero(with_rest)]
+                struct S<T,U>{
+                    #[zero(bound=\"T: Copy\")] a:T,
+                    #[zero(nonzero, bound=\"U: Copy\")] b:U,
+                }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"Zeroable *, *T *: *Copy *, *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "struct S<T>{ #[zero(bound=\"\")] a:T }".to_string(),
+            has_errors: true,
+            expected: r#"Cannot use `#\[zero\(bound=.*\)\]` on a field"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(u8)] enum E<T>{ #[zero(bound(T: Copy))] A(T) }".to_string(),
+            has_errors: true,
+            expected: r#"can only be used on"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(bound(T Copy))] struct S<T>{ a:T }".to_string(),
+            has_errors: true,
+            expected: r#"expected"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zeroable(bound=\"\")] struct S<T>{ a:PhantomData<T>, b:u32 }".to_string(),
+            has_errors: false,
+            expected:
+                r#"S *< *T *> *where *PhantomData *< *T *> *: *:: *zeroable *:: *Zeroable *, *\{"#
+                    .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zeroable(bound=\"T: Copy, U: Copy\")] struct S<T,U>{ a:[T;2] }".to_string(),
+            has_errors: false,
+            expected: r#"where *T *: *Copy *, *U *: *Copy *, *\[ *T *; *2 *\] *: *:: *zeroable *:: *Zeroable *, *\{"#
+                .into(),
+        });
+        testcases.push(TestCase {
+            code: "
+                #[repr(u8)]
+                #[zeroable(bound=\"\")]
+                enum E<T,U>{ A(Option<T>), B(U) }
+            "
+            .to_string(),
+            has_errors: false,
+            expected: r#"where *Option *< *T *> *: *:: *zeroable *:: *Zeroable *, *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zeroable(bound=\"\")] union U<T: Copy>{ #[zero(nonzero)] a:T, b:u8 }"
+                .to_string(),
+            has_errors: false,
+            expected: r#"U *< *T *> *where *\{"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zeroable(nonzero_fields)] union U{ a:u8 }".to_string(),
+            has_errors: true,
+            expected: r#"only supports `bound=.*Use the `#\[zero\(...\)\]` attribute"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "struct S<T>{ #[zeroable(bound=\"\")] a:T }".to_string(),
+            has_errors: true,
+            expected: r#"can only be used on the type"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] struct S<T>{ a:T, b:u32 }".to_string(),
+            has_errors: false,
+            expected:
+                r#"unsafe *impl *< *T *> *:: *zeroable *:: *ffi *:: *ReprC *for *S *< *T *> *where *for *< *'__zeroable *> *T *: *:: *zeroable *:: *ffi *:: *ReprC *, *for *< *'__zeroable *> *u32 *: *:: *zeroable *:: *ffi *:: *ReprC *, *\{"#
+                    .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(u8)] enum E{ A, B }".to_string(),
+            has_errors: false,
+            expected: r#"unsafe *impl *:: *zeroable *:: *ffi *:: *ReprC *for *E"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(u8)] enum E<T>{ A(T), B{ b:u16 } }".to_string(),
+            has_errors: false,
+            expected: r#"ReprC *for *E *< *T *> *where *for *< *'__zeroable *> *T *: *:: *zeroable *:: *ffi *:: *ReprC *, *for *< *'__zeroable *> *u16 *:"#.into(),
+        });
+
+        testcases.push(TestCase {
+            code: "#[zero(size_field=\"cb_size\")] struct S{ cb_size:u32, a:u8 }".to_string(),
+            has_errors: false,
+            expected: r#"fn *new_zeroed *\( *\) *-> *Self *\{.*this *\. *cb_size *= *< *u32 *as *:: *zeroable *:: *ffi *:: *SizeField *>"#
+                .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(init(s_type=\"Ty::A\", b=\"1\"))] struct S(u8); ".to_string(),
+            has_errors: true,
+            expected: r#"The `S` struct has no `s_type` field"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(init(s_type=\"Ty::A\"))] struct S{ s_type:Ty }".to_string(),
+            has_errors: false,
+            expected: r#"this *\. *s_type *= *Ty *:: *A *;"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(size_field=\"cbsize\")] struct S{ cb_size:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"no `cbsize` field.*Did you mean `cb_size`"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(size_field=\"a\")] #[zero(init(a=\"1\"))] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"The `a` field is already set"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(init(a=\"1\", a=\"2\"))] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"The `a` field is already set"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(init(a))] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"Expected `field_name=.*expression.*`"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(u8)] #[zero(size_field=\"a\")] enum E{ A{ a:u8 } }".to_string(),
+            has_errors: true,
+            expected: r#"Cannot use the `#\[zero\(size_field\)\]` attribute on an enum/union"#
+                .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(init(a=\"1\"))] union U{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"Cannot use the `#\[zero\(init\)\]` attribute on an enum/union"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(with_rest, size_field=\"a\")] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"with_rest.*size_field.*can't be used together"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] struct S{ a:u32, b:[u8;4] }".to_string(),
+            has_errors: false,
+            expected: r#"unsafe *impl *:: *zeroable *:: *c_header *:: *CType *for *S.*CTypeDesc *:: *Named *\( *"S" *\).*impl *:: *zeroable *:: *c_header *:: *CHeaderType *for *S.*name *: *"a" *, *ty *: *< *u32 *as *:: *zeroable *:: *c_header *:: *CType *> *:: *C_TYPE"#
+                .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] union U{ a:u32 }".to_string(),
+            has_errors: false,
+            expected: r#"CKind *:: *Union"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] struct S(u32);".to_string(),
+            has_errors: false,
+            expected: r#"name *: *"_0" *, .*offset_of *! *\( *Self *, *0 *\)"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[zero(c_header)] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected:
+                r#"The `#\[zero\(c_header\)\]` attribute requires the type to be `#\[repr\(C\)\]`"#
+                    .into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(transparent)] #[zero(c_header)] struct S{ a:u32 }".to_string(),
+            has_errors: true,
+            expected: r#"requires the type to be `#\[repr\(C\)\]`"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] enum E{ A }".to_string(),
+            has_errors: true,
+            expected: r#"Cannot use the `#\[zero\(c_header\)\]` attribute on an enum"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] struct S<T>{ a:T }".to_string(),
+            has_errors: true,
+            expected: r#"on a type with type or const parameters"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] struct S<const N: usize>{ a:[u8;N] }".to_string(),
+            has_errors: true,
+            expected: r#"on a type with type or const parameters"#.into(),
+        });
+        testcases.push(TestCase {
+            code: "#[repr(C)] #[zero(c_header)] struct S{}".to_string(),
+            has_errors: true,
+            expected: r#"on a type without fields"#.into(),
+        });
         testcases
     };
     let mut errors = Vec::new();
@@ -220,3 +699,90 @@ fn test_compilation() {
         panic!("{:#?}", errors);
     }
 }
+
+#[test]
+fn test_no_warnings() {
+    let no_warnings = vec![
+        "#[zero(not_zeroable(T))] struct S<T>{ a:Option<&'static T>, b:PhantomData<T> }",
+        "#[zero(not_zeroable(T), bound=\"T: Zeroable\")] struct S<T>{ a:PhantomData<T> }",
+        "#[zero(bound=\"T: Zeroable + Copy\")] struct S<T>{ a:T }",
+        "#[zero(bound=\"Vec<T>: Zeroable\")] struct S<T>{ a:T }",
+        "#[zero(allow(redundant_nonzero))] union U{ #[zero(nonzero)] a:u32, b:u8 }",
+        "#[zero(bound=\"\", bound=\"T: Zeroable\")] struct S<T>{ a:T }",
+        "
+            #[zero(not_zeroable(T), allow(ineffective_not_zeroable))]
+            struct S<T>{ a:T }
+        ",
+        "
+            #[repr(u8)]
+            #[zero(not_zeroable(T))]
+            enum E<T>{ A, B(T) }
+        ",
+    ];
+
+    let errors = no_warnings
+        .into_iter()
+        .map(|code| (code, derive_from_str(code)))
+        .filter(|(_, output)| match output {
+            Ok(output) => output.contains("deprecated"),
+            Err(_) => true,
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        panic!("{:#?}", errors);
+    }
+}
+
+#[test]
+fn test_debug_print_file() {
+    // Two types with the same name,as if they were declared in different modules.
+    let types = [
+        "#[zero(debug_print=\"file\")] struct DebugPrintFile{ field:u16 }",
+        "#[zero(debug_print=\"file\")] struct DebugPrintFile{ field:u32 }",
+    ];
+
+    let dir = expansion_dir();
+    let file = dir.join("DebugPrintFile.Zeroable.rs");
+
+    let find_files = || {
+        std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().unwrap().to_str().unwrap();
+                name.starts_with("DebugPrintFile.Zeroable.") && name.ends_with(".rs")
+            })
+            .collect::<Vec<_>>()
+    };
+
+    derive_from_str(types[0]).unwrap();
+    let first = std::fs::read_to_string(&file).unwrap();
+    assert!(first.contains("<u16 as ::zeroable"), "{}", first);
+
+    // Deriving for the same type again overwrites the file.
+    derive_from_str(types[0]).unwrap();
+    assert_eq!(find_files(), vec![file.clone()]);
+
+    // A different type with the same name is written to a file with a hash in the name.
+    derive_from_str(types[1]).unwrap();
+    let files = find_files();
+    let contents = files
+        .iter()
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .collect::<Vec<_>>();
+
+    for path in &files {
+        std::fs::remove_file(path).unwrap();
+    }
+
+    assert_eq!(files.len(), 2, "{:#?}", files);
+    assert!(files.contains(&file));
+    for field_assert in &["<u16 as ::zeroable", "<u32 as ::zeroable"] {
+        let with_field = contents.iter().filter(|x| x.contains(field_assert)).count();
+        assert_eq!(with_field, 1, "{:#?}", contents);
+    }
+    assert!(contents
+        .iter()
+        .all(|x| x.contains("unsafe impl ::zeroable::Zeroable for DebugPrintFile {}")));
+}
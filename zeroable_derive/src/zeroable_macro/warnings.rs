@@ -0,0 +1,174 @@
+//! Warnings for redundant or ineffective attributes,
+//! which can be disabled with the `#[zero(allow(...))]` attribute.
+//!
+//! Since proc macros can't emit warnings on stable Rust,
+//! these are emitted as uses of `#[deprecated]` items,
+//! with the span of the attribute that caused the warning.
+
+use super::attribute_parsing::{IsBounded, Warning, ZeroConfig};
+
+use zeroable_derive_internals::datastructure::{DataStructure, MyField};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::{quote, quote_spanned};
+
+use syn::{spanned::Spanned, Ident, TraitBoundModifier, Type, TypeParamBound, WherePredicate};
+
+/// Emits a function that triggers a deprecation warning for every warning that applies,
+/// or nothing if none apply.
+///
+/// `zeroed_fields` are the fields that are required to be zeroable.
+pub(super) fn emit_warnings(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    zeroed_fields: &[&MyField<'_>],
+) -> TokenStream2 {
+    let is_enabled = |warning: Warning| !config.allowed_warnings().contains(&warning);
+
+    let mut warnings = Vec::<TokenStream2>::new();
+
+    if is_enabled(Warning::IneffectiveNotZeroable) {
+        for param in config.not_zeroable_params() {
+            let requiring_field = zeroed_fields
+                .iter()
+                .find(|field| requires_zeroable(field.ty, param));
+
+            if let Some(field) = requiring_field {
+                let note = format!(
+                    "The `{0}` type parameter is required to be zeroable by the `{1}` field,\
+                     so `#[zero(not_zeroable({0}))]` doesn't remove that requirement.\n\
+                     Use `#[zero(allow(ineffective_not_zeroable))]` to silence this warning.",
+                    param, field.ident,
+                );
+                warnings.push(emit_static_warning(
+                    Warning::IneffectiveNotZeroable,
+                    param.span(),
+                    &note,
+                ));
+            }
+        }
+    }
+
+    if is_enabled(Warning::RedundantBound) {
+        for pred in config.extra_predicates() {
+            if let Some(param) = redundant_bound_param(ds, config, pred) {
+                let note = format!(
+                    "The `{0}: Zeroable` bound is redundant,\
+                     because it's added automatically for type parameters that aren't in \
+                     `#[zero(not_zeroable(...))]` attributes.\n\
+                     Use `#[zero(allow(redundant_bound))]` to silence this warning.",
+                    param,
+                );
+                warnings.push(emit_static_warning(
+                    Warning::RedundantBound,
+                    pred.span(),
+                    &note,
+                ));
+            }
+        }
+    }
+
+    if is_enabled(Warning::RedundantNonzero) {
+        for (index, path) in config.nonzero_attrs() {
+            let ty = ds.variants[index.variant].fields[index.pos].ty;
+            warnings.push(quote_spanned!(path.span()=>
+                {
+                    // Only one of the traits is used,depending on whether the type is zeroable.
+                    #[allow(unused_imports)]
+                    use ::zeroable::maybe_zeroable::{RedundantNonzero as _, NotRedundantNonzero as _};
+                    (&&::zeroable::maybe_zeroable::MaybeZeroable::<#ty>::NEW).redundant_nonzero();
+                }
+            ));
+        }
+    }
+
+    if warnings.is_empty() {
+        return TokenStream2::new();
+    }
+
+    quote!(
+        #[allow(dead_code)]
+        fn _zeroable_warnings_101() {
+            #( #warnings )*
+        }
+    )
+}
+
+/// Emits a use of a deprecated constant named after the warning,
+/// which causes a warning with `note` as the message,at `span`.
+fn emit_static_warning(warning: Warning, span: Span, note: &str) -> TokenStream2 {
+    let def_ident = Ident::new(warning.name(), Span::call_site());
+    let use_ident = Ident::new(warning.name(), span);
+
+    quote!({
+        #[deprecated(note=#note)]
+        #[allow(non_upper_case_globals)]
+        const #def_ident: () = ();
+        let _ = #use_ident;
+    })
+}
+
+/// Whether `ty` requires the `param` type parameter to be zeroable for `ty` to be zeroable.
+///
+/// This only looks at the type parameter itself,and arrays/tuples containing it,
+/// since other generic types may be zeroable regardless of their type arguments
+/// (eg:`PhantomData<T>`,`Option<&T>`).
+fn requires_zeroable(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident(param),
+        Type::Array(array) => requires_zeroable(&array.elem, param),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .any(|elem| requires_zeroable(elem, param)),
+        Type::Paren(paren) => requires_zeroable(&paren.elem, param),
+        Type::Group(group) => requires_zeroable(&group.elem, param),
+        _ => false,
+    }
+}
+
+/// Returns the type parameter if `pred` is a `T: Zeroable` bound
+/// for a type parameter that is already bounded by `Zeroable`.
+fn redundant_bound_param<'a>(
+    ds: &'_ DataStructure<'a>,
+    config: &'_ ZeroConfig<'_>,
+    pred: &WherePredicate,
+) -> Option<&'a Ident> {
+    let pred = match pred {
+        WherePredicate::Type(pred) if pred.lifetimes.is_none() => pred,
+        _ => return None,
+    };
+
+    let bounded_ident = match &pred.bounded_ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident()?,
+        _ => return None,
+    };
+
+    let (pos, param) = ds
+        .generics
+        .type_params()
+        .map(|x| &x.ident)
+        .enumerate()
+        .find(|(_, ident)| *ident == bounded_ident)?;
+
+    let only_zeroable_bounds = pred.bounds.iter().all(|bound| match bound {
+        TypeParamBound::Trait(bound) => {
+            bound.lifetimes.is_none()
+                && matches!(bound.modifier, TraitBoundModifier::None)
+                && bound.path.segments.last().map_or(false, |seg| {
+                    seg.ident == "Zeroable" && seg.arguments.is_empty()
+                })
+        }
+        TypeParamBound::Lifetime(_) => false,
+    });
+
+    if only_zeroable_bounds
+        && !config.no_auto_bounds()
+        && config.unbounded_typarams()[pos] == IsBounded::Yes
+    {
+        Some(param)
+    } else {
+        None
+    }
+}
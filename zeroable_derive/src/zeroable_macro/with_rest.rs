@@ -0,0 +1,101 @@
+use zeroable_derive_internals::{
+    datastructure::{DataStructure, DataVariant, MyField},
+    type_param_usage::TypeParamUsage,
+};
+
+use super::{
+    attribute_parsing::{IsZeroable, ZeroConfig},
+    bounded_where_clause_for,
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, ToTokens};
+
+/// Splits the fields of a struct with the `#[zero(with_rest)]` attribute into
+/// the fields that are zeroed,and the fields that are passed as parameters.
+pub(super) fn split_fields<'a>(
+    ds: &'a DataStructure<'a>,
+    config: &'_ ZeroConfig<'_>,
+) -> (Vec<&'a MyField<'a>>, Vec<&'a MyField<'a>>) {
+    assert_eq!(ds.data_variant, DataVariant::Struct);
+
+    let mut zeroed = Vec::new();
+    let mut params = Vec::new();
+
+    for (zeroableness, field) in config.zeroable_fields().iter().zip(&ds.variants[0].fields) {
+        match zeroableness {
+            IsZeroable::Yes => zeroed.push(field),
+            IsZeroable::No => params.push(field),
+        }
+    }
+
+    (zeroed, params)
+}
+
+/// Emits the `zeroed_with` constructor for a struct with the
+/// `#[zero(with_rest)]` attribute.
+///
+/// Only the type parameters used by the zeroed fields are bounded by `Zeroable`,
+/// since the fields that are passed as parameters don't need to be zeroable.
+pub(super) fn emit_zeroed_with(
+    ds: &'_ DataStructure<'_>,
+    config: &'_ ZeroConfig<'_>,
+    zeroed_fields: &[&MyField<'_>],
+    param_fields: &[&MyField<'_>],
+) -> TokenStream2 {
+    let usage = TypeParamUsage::new(ds);
+    let where_clause = bounded_where_clause_for(
+        ds,
+        config,
+        zeroed_fields,
+        &quote!(::zeroable::Zeroable),
+        |param| usage.is_used_by_any(zeroed_fields.iter().cloned(), param),
+    );
+
+    let name = ds.name;
+    let vis = ds.vis;
+
+    let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+
+    let param_names = param_fields.iter().map(|f| f.ident()).collect::<Vec<_>>();
+    let param_tys = param_fields.iter().map(|f| f.ty);
+    let param_idents = param_fields.iter().map(|f| &f.ident);
+
+    let zeroed_idents = zeroed_fields.iter().map(|f| &f.ident);
+    let zeroed_tys = zeroed_fields.iter().map(|f| f.ty);
+
+    let doc = if param_fields.is_empty() {
+        format!("Constructs a `{}` with every field zeroed.", name)
+    } else {
+        let mut doc = format!(
+            "Constructs a `{}`,taking the value of these fields as parameters:\n\n",
+            name,
+        );
+        for field in param_fields {
+            doc.push_str(&format!(
+                "- `{}: {}`\n\n",
+                field.ident,
+                field.ty.to_token_stream()
+            ));
+        }
+        doc.push_str("Every other field is zeroed.");
+        doc
+    };
+
+    quote!(
+        impl #impl_generics #name #ty_generics
+        #where_clause
+        {
+            #[doc=#doc]
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            #vis fn zeroed_with( #( #param_names: #param_tys, )* ) -> Self {
+                Self{
+                    #( #param_idents: #param_names, )*
+                    #( #zeroed_idents: <#zeroed_tys as ::zeroable::Zeroable>::zeroed(), )*
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,1156 @@
+//! Parsing of the `#[zero(...)]` attributes of the zeroable derive macros,
+//! and of the `#[repr(...)]` and `#[zeroable(bound="...")]` attributes that they use.
+
+use crate::{
+    attribute_parsing_shared::{most_similar, with_nested_meta},
+    datastructure::{DataStructure, DataVariant, FieldIndex, MyField, Struct},
+    repr_attr::ReprAttr,
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{Comma, Paren},
+    Attribute, Expr, Ident, Lit, LitStr, Meta, MetaNameValue, NestedMeta, Path, WherePredicate,
+};
+
+use quote::ToTokens;
+
+use std::{collections::HashSet, marker::PhantomData};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Where the `#[zero(debug_print)]` attribute prints the output of the derive macro.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebugPrint {
+    /// Emits a compile-time error with the pretty-printed output.
+    Error,
+    /// Writes the pretty-printed output to a file,and continues compilation.
+    File,
+}
+
+/// A warning that can be disabled with the `#[zero(allow(...))]` attribute.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Warning {
+    /// A `#[zero(not_zeroable(T))]` for a `T` that a zeroed field requires to be zeroable.
+    IneffectiveNotZeroable,
+    /// A `#[zero(nonzero)]` on a field whose type is zeroable.
+    RedundantNonzero,
+    /// A `#[zero(bound="T: Zeroable")]` for a `T` that already has that bound.
+    RedundantBound,
+}
+
+impl Warning {
+    /// All the warnings.
+    pub const ALL: &'static [Warning] = &[
+        Warning::IneffectiveNotZeroable,
+        Warning::RedundantNonzero,
+        Warning::RedundantBound,
+    ];
+
+    /// The name of the warning,as used in the `#[zero(allow(...))]` attribute.
+    pub fn name(self) -> &'static str {
+        match self {
+            Warning::IneffectiveNotZeroable => "ineffective_not_zeroable",
+            Warning::RedundantNonzero => "redundant_nonzero",
+            Warning::RedundantBound => "redundant_bound",
+        }
+    }
+
+    /// Gets the warning with the `name` name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().cloned().find(|w| w.name() == name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The configuration of a derive macro,parsed from the attributes of the type.
+///
+/// The accessors that are hidden from the documentation are used by
+/// the derive macros of the `zeroable` crate,and aren't part of the stable API.
+#[non_exhaustive]
+pub struct ZeroConfig<'a> {
+    /// The predicates from type-level `#[zero(bound...)]` attributes.
+    extra_predicates: Vec<WherePredicate>,
+
+    /// The type parameters that don't have a `Zeroable` bound.
+    unbounded_typarams: Vec<IsBounded>,
+
+    /// The predicates from field-level `#[zero(bound(...))]` attributes,
+    /// which are only added when the field is zeroed.
+    field_predicates: Vec<(FieldIndex, WherePredicate)>,
+
+    /// Whether no type parameter has an automatic `Zeroable` bound,
+    /// from the `#[zero(bound="")]` attribute.
+    no_auto_bounds: bool,
+
+    /// Whether the types of zeroed fields that use type parameters are bounded,
+    /// instead of the type parameters themselves,
+    /// from the bytemuck_derive-compatible `#[zeroable(bound="...")]` attribute.
+    field_type_bounds: bool,
+
+    /// Code that's inserted alongside the Zeroable assertions.
+    /// Used in tests.
+    test_code: Vec<TokenStream2>,
+
+    /// Where to print the output of the derive macro,
+    /// from the `#[zero(debug_print)]` attribute.
+    debug_print: Option<DebugPrint>,
+
+    /// Whether each field is required to be zeroable,
+    /// for unions,and structs with the `#[zero(with_rest)]` attribute
+    /// (this is empty for other types).
+    zeroable_fields: Vec<IsZeroable>,
+    /// Whether fields are required to be zeroable by default,
+    /// which is `IsZeroable::No` with the `#[zero(nonzero_fields)]` attribute.
+    default_zeroab: IsZeroable,
+
+    /// The representation of the type,from its `#[repr(...)]` attributes.
+    repr_attr: ReprAttr,
+
+    /// The foreign type that this type mirrors,
+    /// from the `#[zero(remote="...")]` attribute.
+    remote: Option<Path>,
+
+    /// The newtype wrapping the foreign type,
+    /// from the `#[zero(remote_newtype="...")]` attribute.
+    remote_newtype: Option<Ident>,
+
+    /// Whether to generate a `zeroed_with` constructor,
+    /// which takes the `#[zero(nonzero)]` fields as parameters.
+    with_rest: bool,
+
+    /// Whether to generate the `is_zero_variant` method and
+    /// the `ZERO_VARIANT_NAME` associated constant for an enum.
+    variant_info: bool,
+
+    /// Whether to implement the `ZeroableLayout` trait.
+    layout_info: bool,
+
+    /// Whether to implement the `CType` and `CHeaderType` traits,
+    /// from the `#[zero(c_header)]` attribute.
+    c_header: bool,
+
+    /// The type parameters listed in `#[zero(not_zeroable(...))]` attributes,
+    /// used to warn about the ones that are required to be zeroable anyway.
+    not_zeroable_params: Vec<Ident>,
+
+    /// The fields with a `#[zero(nonzero)]` attribute,with the path in the attribute,
+    /// used to warn about the fields whose type is zeroable anyway.
+    nonzero_attrs: Vec<(FieldIndex, Path)>,
+
+    /// The warnings disabled with `#[zero(allow(...))]` attributes.
+    allowed_warnings: HashSet<Warning>,
+
+    /// The fields that the `Reset` derive doesn't zero,
+    /// from the `#[zero(keep)]` attribute.
+    kept_fields: HashSet<FieldIndex>,
+
+    /// The field that `new_zeroed` sets to the size of the struct,
+    /// from the `#[zero(size_field="...")]` attribute.
+    size_field: Option<FieldIndex>,
+
+    /// The fields that `new_zeroed` sets,with the expressions they're set to,
+    /// from `#[zero(init(...))]` attributes.
+    init_fields: Vec<(FieldIndex, Expr)>,
+
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> ZeroConfig<'a> {
+    fn new(za: ZeroableAttrs<'a>) -> Result<Self, syn::Error> {
+        let ZeroableAttrs {
+            extra_predicates,
+            unbounded_typarams,
+            field_predicates,
+            no_auto_bounds,
+            field_type_bounds,
+            test_code,
+            debug_print,
+            zeroable_fields,
+            default_zeroab,
+            repr_attr,
+            remote,
+            remote_newtype,
+            with_rest,
+            variant_info,
+            layout_info,
+            c_header,
+            nonzero_fields,
+            not_zeroable_params,
+            nonzero_attrs,
+            allowed_warnings,
+            kept_fields,
+            size_field,
+            init_fields,
+            seen_attrs: _,
+            _marker,
+        } = za;
+
+        if let (None, Some(newtype)) = (&remote, &remote_newtype) {
+            return_spanned_err! {
+                newtype,
+                "The `#[zero(remote_newtype)]` attribute requires a `#[zero(remote)]` attribute",
+            }
+        }
+
+        if let Some(path) = &nonzero_fields {
+            if zeroable_fields.is_empty() {
+                return_spanned_err! {
+                    path,
+                    "Cannot use the `#[zero(nonzero_fields)]` attribute on a struct/enum,\
+                     unless it's a struct with the `#[zero(with_rest)]` attribute",
+                }
+            }
+        }
+
+        if let Some(path) = &c_header {
+            if !matches!(
+                repr_attr,
+                ReprAttr::C {
+                    integer_repr: false
+                }
+            ) {
+                return_spanned_err! {
+                    path,
+                    "The `#[zero(c_header)]` attribute requires the type to be `#[repr(C)]`",
+                }
+            }
+        }
+
+        Ok(Self {
+            extra_predicates,
+            unbounded_typarams,
+            field_predicates,
+            no_auto_bounds,
+            field_type_bounds,
+            test_code,
+            debug_print,
+            zeroable_fields,
+            default_zeroab,
+            repr_attr,
+            remote,
+            remote_newtype,
+            with_rest,
+            variant_info,
+            layout_info,
+            c_header: c_header.is_some(),
+            not_zeroable_params,
+            nonzero_attrs,
+            allowed_warnings,
+            kept_fields,
+            size_field,
+            init_fields,
+            _marker,
+        })
+    }
+}
+
+/// Accessors that are part of the stable API.
+impl<'a> ZeroConfig<'a> {
+    /// The representation of the type,from its `#[repr(...)]` attributes.
+    pub fn repr_attr(&self) -> ReprAttr {
+        self.repr_attr
+    }
+
+    /// The predicates from type-level `#[zero(bound...)]` attributes.
+    pub fn extra_predicates(&self) -> &[WherePredicate] {
+        &self.extra_predicates
+    }
+
+    /// Whether the `index` field is required to be zeroable.
+    ///
+    /// This is false for the fields of unions and `#[zero(with_rest)]` structs that
+    /// are `#[zero(nonzero)]`,or aren't `#[zero(zeroable)]` with `#[zero(nonzero_fields)]`.
+    ///
+    /// For transparent unions,only the first field is required to be zeroable,
+    /// regardless of what this returns.
+    pub fn is_zeroable_field(&self, index: FieldIndex) -> bool {
+        self.zeroable_fields.get(index.pos) != Some(&IsZeroable::No)
+    }
+
+    /// Whether the derive macro adds a `Zeroable` bound to the
+    /// type parameter at position `pos` (counting only type parameters).
+    ///
+    /// This is false for the type parameters in `#[zero(not_zeroable(...))]` attributes,
+    /// and for every type parameter with the `#[zero(bound="")]` attribute.
+    pub fn has_auto_bound(&self, pos: usize) -> bool {
+        !self.no_auto_bounds && self.unbounded_typarams.get(pos) == Some(&IsBounded::Yes)
+    }
+
+    /// Where to print the output of the derive macro,
+    /// from the `#[zero(debug_print)]` attribute.
+    pub fn debug_print(&self) -> Option<DebugPrint> {
+        self.debug_print
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ZeroConfig<'a> {
+    pub fn unbounded_typarams(&self) -> &[IsBounded] {
+        &self.unbounded_typarams
+    }
+
+    pub fn field_predicates(&self) -> &[(FieldIndex, WherePredicate)] {
+        &self.field_predicates
+    }
+
+    pub fn no_auto_bounds(&self) -> bool {
+        self.no_auto_bounds
+    }
+
+    pub fn field_type_bounds(&self) -> bool {
+        self.field_type_bounds
+    }
+
+    pub fn test_code(&self) -> &[TokenStream2] {
+        &self.test_code
+    }
+
+    pub fn zeroable_fields(&self) -> &[IsZeroable] {
+        &self.zeroable_fields
+    }
+
+    pub fn default_zeroab(&self) -> IsZeroable {
+        self.default_zeroab
+    }
+
+    pub fn remote(&self) -> Option<&Path> {
+        self.remote.as_ref()
+    }
+
+    pub fn remote_newtype(&self) -> Option<&Ident> {
+        self.remote_newtype.as_ref()
+    }
+
+    pub fn with_rest(&self) -> bool {
+        self.with_rest
+    }
+
+    pub fn variant_info(&self) -> bool {
+        self.variant_info
+    }
+
+    pub fn layout_info(&self) -> bool {
+        self.layout_info
+    }
+
+    pub fn c_header(&self) -> bool {
+        self.c_header
+    }
+
+    pub fn not_zeroable_params(&self) -> &[Ident] {
+        &self.not_zeroable_params
+    }
+
+    pub fn nonzero_attrs(&self) -> &[(FieldIndex, Path)] {
+        &self.nonzero_attrs
+    }
+
+    pub fn allowed_warnings(&self) -> &HashSet<Warning> {
+        &self.allowed_warnings
+    }
+
+    pub fn kept_fields(&self) -> &HashSet<FieldIndex> {
+        &self.kept_fields
+    }
+
+    pub fn size_field(&self) -> Option<FieldIndex> {
+        self.size_field
+    }
+
+    pub fn init_fields(&self) -> &[(FieldIndex, Expr)] {
+        &self.init_fields
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether a type parameter has the automatic bound of the derive macro.
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IsBounded {
+    Yes,
+    No,
+}
+
+/// Whether a field is required to be zeroable.
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IsZeroable {
+    Yes,
+    No,
+}
+
+impl IsZeroable {
+    /// Converts a bool to an `IsZeroable`.
+    pub fn new(val: bool) -> Self {
+        match val {
+            true => IsZeroable::Yes,
+            false => IsZeroable::No,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct ZeroableAttrs<'a> {
+    extra_predicates: Vec<WherePredicate>,
+    unbounded_typarams: Vec<IsBounded>,
+    field_predicates: Vec<(FieldIndex, WherePredicate)>,
+    no_auto_bounds: bool,
+    field_type_bounds: bool,
+    test_code: Vec<TokenStream2>,
+    debug_print: Option<DebugPrint>,
+    zeroable_fields: Vec<IsZeroable>,
+    default_zeroab: IsZeroable,
+    repr_attr: ReprAttr,
+    remote: Option<Path>,
+    remote_newtype: Option<Ident>,
+    with_rest: bool,
+    variant_info: bool,
+    layout_info: bool,
+    c_header: Option<Path>,
+    nonzero_fields: Option<Path>,
+    not_zeroable_params: Vec<Ident>,
+    nonzero_attrs: Vec<(FieldIndex, Path)>,
+    allowed_warnings: HashSet<Warning>,
+    kept_fields: HashSet<FieldIndex>,
+    size_field: Option<FieldIndex>,
+    init_fields: Vec<(FieldIndex, Expr)>,
+    /// The attributes that were already used on the type (with `None`),and on each field,
+    /// to error on duplicated and conflicting attributes.
+    seen_attrs: HashSet<(Option<FieldIndex>, String)>,
+    _marker: PhantomData<&'a ()>,
+}
+
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+enum ParseContext<'a> {
+    TypeAttr {
+        ds: &'a DataStructure<'a>,
+    },
+    Variant {
+        variant: &'a Struct<'a>,
+    },
+    Field {
+        ds: &'a DataStructure<'a>,
+        field: &'a MyField<'a>,
+    },
+}
+
+/// Parses the attributes of the type,its variants,and its fields.
+///
+/// This returns an error for invalid attributes,
+/// and for attributes that aren't valid for the kind of type (struct,enum,or union).
+pub fn parse_attrs_for_zeroed<'a>(ds: &'a DataStructure<'a>) -> Result<ZeroConfig<'a>, syn::Error> {
+    let typaram_count = ds.generics.type_params().count();
+
+    let mut this = ZeroableAttrs {
+        extra_predicates: Vec::new(),
+        unbounded_typarams: vec![IsBounded::Yes; typaram_count],
+        field_predicates: Vec::new(),
+        no_auto_bounds: false,
+        field_type_bounds: false,
+        test_code: Vec::new(),
+        debug_print: None,
+        zeroable_fields: if ds.data_variant == DataVariant::Union {
+            vec![IsZeroable::Yes; ds.variants[0].fields.len()]
+        } else {
+            Vec::new()
+        },
+        default_zeroab: IsZeroable::Yes,
+        repr_attr: ReprAttr::Rust,
+        remote: None,
+        remote_newtype: None,
+        with_rest: false,
+        variant_info: false,
+        layout_info: false,
+        c_header: None,
+        nonzero_fields: None,
+        not_zeroable_params: Vec::new(),
+        nonzero_attrs: Vec::new(),
+        allowed_warnings: HashSet::new(),
+        kept_fields: HashSet::new(),
+        size_field: None,
+        init_fields: Vec::new(),
+        seen_attrs: HashSet::new(),
+        _marker: PhantomData,
+    };
+
+    parse_inner(&mut this, ds.attrs, ParseContext::TypeAttr { ds })?;
+
+    for variant in &ds.variants {
+        parse_inner(&mut this, variant.attrs, ParseContext::Variant { variant })?;
+        for field in &variant.fields {
+            parse_inner(&mut this, field.attrs, ParseContext::Field { ds, field })?;
+        }
+    }
+
+    ZeroConfig::new(this)
+}
+
+fn parse_inner<'a, I>(
+    this: &mut ZeroableAttrs<'a>,
+    attrs: I,
+    pctx: ParseContext<'a>,
+) -> Result<(), syn::Error>
+where
+    I: IntoIterator<Item = &'a Attribute>,
+{
+    for attr in attrs {
+        if attr.path.is_ident("zero") {
+            let zero_attrs =
+                attr.parse_args_with(Punctuated::<ZeroAttr, Comma>::parse_terminated)?;
+            for zero_attr in zero_attrs {
+                match zero_attr {
+                    ZeroAttr::Meta(meta) => parse_sabi_attr(this, pctx, meta)?,
+                    ZeroAttr::BoundList { path, predicates } => {
+                        add_bounds(this, pctx, &path, predicates)?
+                    }
+                }
+            }
+        } else if attr.path.is_ident("zeroable") {
+            parse_bytemuck_attr(this, pctx, attr)?;
+        } else if attr.path.is_ident("repr") {
+            if let Meta::List(list) = attr.parse_meta()? {
+                this.repr_attr = ReprAttr::new(list.nested)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses the `#[zeroable(bound="...")]` attribute from bytemuck_derive.
+///
+/// Like in bytemuck_derive,this replaces the automatic `Zeroable` bounds
+/// for type parameters with `Zeroable` bounds for the types of the zeroed fields,
+/// and adds the predicates in the string.
+fn parse_bytemuck_attr<'a>(
+    this: &mut ZeroableAttrs<'a>,
+    pctx: ParseContext<'a>,
+    attr: &Attribute,
+) -> Result<(), syn::Error> {
+    if !matches!(pctx, ParseContext::TypeAttr { .. }) {
+        return_spanned_err! {
+            attr,
+            "The `#[zeroable(...)]` attribute can only be used on the type",
+        }
+    }
+
+    let metas = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+    for meta in metas {
+        match &meta {
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(value),
+                path,
+                ..
+            }) if path.is_ident("bound") => {
+                let predicates =
+                    value.parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)?;
+                this.extra_predicates.extend(predicates);
+                this.no_auto_bounds = true;
+                this.field_type_bounds = true;
+            }
+            _ => return_spanned_err! {
+                meta,
+                "The `#[zeroable(...)]` attribute only supports `bound=\"...\"`,\
+                 for compatibility with bytemuck_derive.\n\
+                 Use the `#[zero(...)]` attribute for everything else.",
+            },
+        }
+    }
+    Ok(())
+}
+
+/// An attribute inside `#[zero(...)]`.
+enum ZeroAttr {
+    Meta(Meta),
+    /// The `bound(...)` attribute,which contains where predicates.
+    BoundList {
+        path: Path,
+        predicates: Punctuated<WherePredicate, Comma>,
+    },
+}
+
+impl Parse for ZeroAttr {
+    fn parse(input: ParseStream<'_>) -> Result<Self, syn::Error> {
+        let fork = input.fork();
+        if fork.parse::<Path>()?.is_ident("bound") && fork.peek(Paren) {
+            let path = input.parse::<Path>()?;
+            let content;
+            parenthesized!(content in input);
+            let predicates = content.parse_terminated(WherePredicate::parse)?;
+            return Ok(ZeroAttr::BoundList { path, predicates });
+        }
+
+        match input.parse::<NestedMeta>()? {
+            NestedMeta::Meta(meta) => Ok(ZeroAttr::Meta(meta)),
+            NestedMeta::Lit(lit) => {
+                return_spanned_err!(
+                    lit,
+                    "the #[zero(...)] attribute does not allow literals in the attribute list",
+                )
+            }
+        }
+    }
+}
+
+/// Adds the predicates of a `#[zero(bound...)]` attribute,
+/// to the impl if it's on the type,
+/// or to the predicates that apply when the field is zeroed if it's on a field.
+fn add_bounds<'a, I>(
+    this: &mut ZeroableAttrs<'a>,
+    pctx: ParseContext<'a>,
+    path: &Path,
+    predicates: I,
+) -> Result<(), syn::Error>
+where
+    I: IntoIterator<Item = WherePredicate>,
+{
+    match pctx {
+        ParseContext::TypeAttr { .. } => {
+            this.extra_predicates.extend(predicates);
+        }
+        ParseContext::Field { field, .. } => {
+            this.field_predicates
+                .extend(predicates.into_iter().map(|pred| (field.index, pred)));
+        }
+        ParseContext::Variant { .. } => {
+            return Err(unrecognized_attr(path, AttrForm::List, pctx));
+        }
+    }
+    Ok(())
+}
+
+fn parse_sabi_attr<'a>(
+    this: &mut ZeroableAttrs<'a>,
+    pctx: ParseContext<'a>,
+    attr: Meta,
+) -> Result<(), syn::Error> {
+    check_repeated_attr(this, pctx, &attr)?;
+
+    match (pctx, attr) {
+        (
+            ParseContext::TypeAttr { .. },
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(ref value),
+                ref path,
+                ..
+            }),
+        ) => {
+            if path.is_ident("bound") {
+                if value.value().trim().is_empty() {
+                    this.no_auto_bounds = true;
+                } else {
+                    add_bounds(this, pctx, path, Some(value.parse()?))?;
+                }
+            } else if path.is_ident("size_field") {
+                let ds = struct_only(pctx, path)?;
+                let field = find_field(ds, value)?;
+                if this
+                    .init_fields
+                    .iter()
+                    .any(|(index, _)| *index == field.index)
+                {
+                    return Err(initialized_twice(value, field));
+                }
+                this.size_field = Some(field.index);
+            } else if path.is_ident("remote") {
+                this.remote = Some(value.parse()?);
+            } else if path.is_ident("remote_newtype") {
+                this.remote_newtype = Some(value.parse()?);
+            } else if path.is_ident("debug_print") {
+                this.debug_print = Some(match &*value.value() {
+                    "error" => DebugPrint::Error,
+                    "file" => DebugPrint::File,
+                    _ => return_spanned_err! {
+                        value,
+                        "Expected either `debug_print=\"error\"` or `debug_print=\"file\"`",
+                    },
+                });
+            } else if path.is_ident("_test_code") {
+                this.test_code.push(value.parse()?);
+            } else {
+                return Err(unrecognized_attr(path, AttrForm::NameValue, pctx));
+            }
+        }
+        (ParseContext::TypeAttr { ds }, Meta::List(list)) => {
+            if list.path.is_ident("not_zeroable") {
+                with_nested_meta("not_zeroable", list.nested, |attr| match &attr {
+                    Meta::Path(path) => {
+                        let tyident = path.get_ident().ok_or_else(|| {
+                            spanned_err! {path,"Expected identifier for type parameter"}
+                        })?;
+
+                        let pos = ds
+                            .generics
+                            .type_params()
+                            .position(|x| x.ident == *tyident)
+                            .ok_or_else(|| spanned_err! {tyident,"Expected a type parameter"})?;
+
+                        if this.unbounded_typarams[pos] == IsBounded::No {
+                            return_spanned_err! {
+                                tyident,
+                                "The `{}` type parameter is listed more than once \
+                                 in `#[zero(not_zeroable(...))]` attributes",
+                                tyident,
+                            }
+                        }
+
+                        this.unbounded_typarams[pos] = IsBounded::No;
+                        this.not_zeroable_params.push(tyident.clone());
+
+                        Ok(())
+                    }
+                    _ => {
+                        return_spanned_err! {attr,"Expected identifier for type parameter"}
+                    }
+                })?;
+            } else if list.path.is_ident("init") {
+                struct_only(pctx, &list.path)?;
+                with_nested_meta("init", list.nested, |attr| match &attr {
+                    Meta::NameValue(MetaNameValue {
+                        lit: Lit::Str(value),
+                        path,
+                        ..
+                    }) => {
+                        let name = path.to_token_stream().to_string();
+                        let field = find_field(ds, &LitStr::new(&name, path.span()))?;
+                        let already_set = this.size_field == Some(field.index)
+                            || this
+                                .init_fields
+                                .iter()
+                                .any(|(index, _)| *index == field.index);
+                        if already_set {
+                            return Err(initialized_twice(path, field));
+                        }
+                        this.init_fields.push((field.index, value.parse()?));
+                        Ok(())
+                    }
+                    _ => {
+                        return_spanned_err! {attr,"Expected `field_name=\"expression\"`"}
+                    }
+                })?;
+            } else if list.path.is_ident("allow") {
+                with_nested_meta("allow", list.nested, |attr| match &attr {
+                    Meta::Path(path) => {
+                        let name = path.to_token_stream().to_string();
+                        match Warning::from_name(&name) {
+                            Some(warning) => {
+                                this.allowed_warnings.insert(warning);
+                                Ok(())
+                            }
+                            None => Err(unrecognized_warning(path, &name)),
+                        }
+                    }
+                    _ => {
+                        return_spanned_err! {attr,"Expected the name of a warning"}
+                    }
+                })?;
+            } else {
+                return Err(unrecognized_attr(&list.path, AttrForm::List, pctx));
+            }
+        }
+        (ParseContext::TypeAttr { ds }, Meta::Path(path)) => {
+            if path.is_ident("debug_print") {
+                this.debug_print = Some(DebugPrint::Error);
+            } else if path.is_ident("with_rest") {
+                if ds.data_variant != DataVariant::Struct {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(with_rest)]` attribute on an enum/union",
+                    }
+                }
+
+                this.with_rest = true;
+                this.zeroable_fields = vec![this.default_zeroab; ds.variants[0].fields.len()];
+            } else if path.is_ident("variant_info") {
+                if ds.data_variant != DataVariant::Enum {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(variant_info)]` attribute on a struct/union",
+                    }
+                }
+
+                this.variant_info = true;
+            } else if path.is_ident("layout_info") {
+                if ds.data_variant == DataVariant::Enum {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(layout_info)]` attribute on an enum",
+                    }
+                }
+
+                this.layout_info = true;
+            } else if path.is_ident("c_header") {
+                if ds.data_variant == DataVariant::Enum {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(c_header)]` attribute on an enum",
+                    }
+                }
+                if ds.generics.type_params().next().is_some()
+                    || ds.generics.const_params().next().is_some()
+                {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(c_header)]` attribute on a type with \
+                         type or const parameters",
+                    }
+                }
+                if ds.variants[0].fields.is_empty() {
+                    return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero(c_header)]` attribute on a type without fields,\
+                         since C doesn't allow empty structs/unions",
+                    }
+                }
+
+                this.c_header = Some(path.clone());
+            } else if path.is_ident("nonzero_fields") {
+                this.default_zeroab = IsZeroable::No;
+                this.nonzero_fields = Some(path.clone());
+
+                for zf in &mut this.zeroable_fields {
+                    *zf = IsZeroable::No;
+                }
+            } else {
+                return Err(unrecognized_attr(&path, AttrForm::Path, pctx));
+            }
+        }
+        (ParseContext::Field { ds, field }, Meta::Path(path)) => {
+            let is_zeroable;
+
+            if {
+                is_zeroable = path.is_ident("zeroable");
+                is_zeroable || path.is_ident("nonzero")
+            } {
+                match this.zeroable_fields.get_mut(field.index.pos) {
+                    Some(zf) => {
+                        *zf = IsZeroable::new(is_zeroable);
+                        if !is_zeroable {
+                            this.nonzero_attrs.push((field.index, path.clone()));
+                        }
+                    }
+                    None => return_spanned_err! {
+                        path,
+                        "Cannot use the `#[zero({})]` attribute on a struct/enum field,\
+                         unless it's in a struct with the `#[zero(with_rest)]` attribute",
+                        path.to_token_stream(),
+                    },
+                }
+            } else if path.is_ident("keep") {
+                if ds.data_variant == DataVariant::Union {
+                    return_spanned_err! {path,"Cannot use the `#[zero(keep)]` attribute on a union field"}
+                }
+                this.kept_fields.insert(field.index);
+            } else {
+                return Err(unrecognized_attr(&path, AttrForm::Path, pctx));
+            }
+        }
+        (
+            ParseContext::Field { .. },
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(ref value),
+                ref path,
+                ..
+            }),
+        ) if path.is_ident("bound") => {
+            if value.value().trim().is_empty() {
+                return_spanned_err! {
+                    value,
+                    "Cannot use `#[zero(bound=\"\")]` on a field,\
+                     it can only be used on the type to remove the automatic bounds",
+                }
+            }
+            add_bounds(this, pctx, path, Some(value.parse()?))?;
+        }
+        (_, attr) => {
+            return Err(unrecognized_attr(attr.path(), AttrForm::of(&attr), pctx));
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The syntactic form of an attribute inside `#[zero(...)]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum AttrForm {
+    /// eg:`#[zero(keep)]`
+    Path,
+    /// eg:`#[zero(bound="T: Copy")]`
+    NameValue,
+    /// eg:`#[zero(not_zeroable(T))]`
+    List,
+}
+
+impl AttrForm {
+    fn of(meta: &Meta) -> Self {
+        match meta {
+            Meta::Path(_) => AttrForm::Path,
+            Meta::NameValue(_) => AttrForm::NameValue,
+            Meta::List(_) => AttrForm::List,
+        }
+    }
+
+    fn display(self, name: &str) -> String {
+        match self {
+            AttrForm::Path => format!("`#[zero({})]`", name),
+            AttrForm::NameValue => format!("`#[zero({}=\"...\")]`", name),
+            AttrForm::List => format!("`#[zero({}(...))]`", name),
+        }
+    }
+}
+
+/// Where an attribute inside `#[zero(...)]` can be used.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum AttrPosition {
+    Type,
+    Variant,
+    Field,
+}
+
+impl AttrPosition {
+    fn of(pctx: ParseContext<'_>) -> Self {
+        match pctx {
+            ParseContext::TypeAttr { .. } => AttrPosition::Type,
+            ParseContext::Variant { .. } => AttrPosition::Variant,
+            ParseContext::Field { .. } => AttrPosition::Field,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            AttrPosition::Type => "the type",
+            AttrPosition::Variant => "enum variants",
+            AttrPosition::Field => "fields",
+        }
+    }
+}
+
+/// The documented attributes inside `#[zero(...)]`.
+const VALID_ATTRS: &[(&str, AttrForm, AttrPosition)] = &[
+    ("bound", AttrForm::NameValue, AttrPosition::Type),
+    ("bound", AttrForm::List, AttrPosition::Type),
+    ("not_zeroable", AttrForm::List, AttrPosition::Type),
+    ("allow", AttrForm::List, AttrPosition::Type),
+    ("nonzero_fields", AttrForm::Path, AttrPosition::Type),
+    ("with_rest", AttrForm::Path, AttrPosition::Type),
+    ("variant_info", AttrForm::Path, AttrPosition::Type),
+    ("layout_info", AttrForm::Path, AttrPosition::Type),
+    ("c_header", AttrForm::Path, AttrPosition::Type),
+    ("size_field", AttrForm::NameValue, AttrPosition::Type),
+    ("init", AttrForm::List, AttrPosition::Type),
+    ("remote", AttrForm::NameValue, AttrPosition::Type),
+    ("remote_newtype", AttrForm::NameValue, AttrPosition::Type),
+    ("debug_print", AttrForm::Path, AttrPosition::Type),
+    ("debug_print", AttrForm::NameValue, AttrPosition::Type),
+    ("bound", AttrForm::NameValue, AttrPosition::Field),
+    ("bound", AttrForm::List, AttrPosition::Field),
+    ("zeroable", AttrForm::Path, AttrPosition::Field),
+    ("nonzero", AttrForm::Path, AttrPosition::Field),
+    ("keep", AttrForm::Path, AttrPosition::Field),
+];
+
+/// Attributes that can't be used together on the same type/field.
+const CONFLICTING_ATTRS: &[(&str, &str)] = &[
+    ("zeroable", "nonzero"),
+    ("with_rest", "size_field"),
+    ("with_rest", "init"),
+];
+
+/// Attributes that can be used multiple times on the same type/field.
+const REPEATABLE_ATTRS: &[&str] = &["bound", "not_zeroable", "allow", "init", "_test_code"];
+
+/// Errors if `attr` was already used on the same type/field,
+/// or if it conflicts with an attribute that was.
+fn check_repeated_attr<'a>(
+    this: &mut ZeroableAttrs<'a>,
+    pctx: ParseContext<'a>,
+    attr: &Meta,
+) -> Result<(), syn::Error> {
+    let name = attr.path().to_token_stream().to_string();
+
+    if REPEATABLE_ATTRS.contains(&&*name) {
+        return Ok(());
+    }
+
+    let field = match pctx {
+        ParseContext::TypeAttr { .. } => None,
+        ParseContext::Field { field, .. } => Some(field.index),
+        // There are no valid variant attributes,so they're all errors already.
+        ParseContext::Variant { .. } => return Ok(()),
+    };
+
+    for &(left, right) in CONFLICTING_ATTRS {
+        let other = match &*name {
+            x if x == left => right,
+            x if x == right => left,
+            _ => continue,
+        };
+        if this.seen_attrs.contains(&(field, other.to_string())) {
+            return_spanned_err! {
+                attr,
+                "The `#[zero({})]` and `#[zero({})]` attributes can't be used together on {}",
+                other,
+                name,
+                match field {
+                    Some(_) => "the same field",
+                    None => "the same type",
+                },
+            }
+        }
+    }
+
+    if !this.seen_attrs.insert((field, name.clone())) {
+        return_spanned_err! {
+            attr,
+            "Duplicate `#[zero({})]` attribute{}",
+            name,
+            match field {
+                Some(_) => " on this field",
+                None => "",
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Errors if the attribute isn't on a struct,
+/// for the attributes that generate the `new_zeroed` constructor.
+fn struct_only<'a>(
+    pctx: ParseContext<'a>,
+    path: &Path,
+) -> Result<&'a DataStructure<'a>, syn::Error> {
+    match pctx {
+        ParseContext::TypeAttr { ds } if ds.data_variant == DataVariant::Struct => Ok(ds),
+        _ => return_spanned_err! {
+            path,
+            "Cannot use the `#[zero({})]` attribute on an enum/union",
+            path.to_token_stream(),
+        },
+    }
+}
+
+/// Finds the field of a struct named `name`,
+/// which is the position of the field for tuple structs.
+fn find_field<'a>(ds: &'a DataStructure<'a>, name: &LitStr) -> Result<&'a MyField<'a>, syn::Error> {
+    use std::fmt::Write;
+
+    let fields = &ds.variants[0].fields;
+    let name_str = name.value();
+
+    if let Some(field) = fields.iter().find(|f| f.ident.to_string() == name_str) {
+        return Ok(field);
+    }
+
+    let mut msg = format!("The `{}` struct has no `{}` field", ds.name, name_str);
+    let names = fields
+        .iter()
+        .map(|f| f.ident.to_string())
+        .collect::<Vec<_>>();
+    if let Some(similar) = most_similar(&name_str, names.iter().map(|x| &**x)) {
+        let _ = write!(msg, "\n\nDid you mean `{}`?", similar);
+    }
+    return_spanned_err! {name,"{}",msg}
+}
+
+/// Constructs the error for a field that's set by more than one
+/// `#[zero(size_field="...")]`/`#[zero(init(...))]` attribute.
+fn initialized_twice(tokens: &dyn ToTokens, field: &MyField<'_>) -> syn::Error {
+    spanned_err! {
+        tokens,
+        "The `{}` field is already set by a \
+         `#[zero(size_field=\"...\")]` or `#[zero(init(...))]` attribute",
+        field.ident,
+    }
+}
+
+/// Constructs the error for an unrecognized attribute inside `#[zero(...)]`,
+/// suggesting similarly named attributes.
+fn unrecognized_attr(path: &Path, form: AttrForm, pctx: ParseContext<'_>) -> syn::Error {
+    use std::fmt::Write;
+
+    let name = path.to_token_stream().to_string();
+    let position = AttrPosition::of(pctx);
+
+    let mut msg = format!("Unrecognized attribute: {}", form.display(&name));
+
+    let here = VALID_ATTRS
+        .iter()
+        .filter(|(_, _, pos)| *pos == position)
+        .collect::<Vec<_>>();
+
+    if here.iter().any(|(vname, _, _)| *vname == name) {
+        let forms = here
+            .iter()
+            .filter(|(vname, _, _)| *vname == name)
+            .map(|(vname, vform, _)| vform.display(vname))
+            .collect::<Vec<_>>();
+        let _ = write!(
+            msg,
+            "\n\nExpected it to be written as {}",
+            forms.join(" or ")
+        );
+    } else if let Some((_, _, elsewhere)) = VALID_ATTRS.iter().find(|(vname, _, _)| *vname == name)
+    {
+        let _ = write!(
+            msg,
+            "\n\nThe `{}` attribute can only be used on {}",
+            name,
+            elsewhere.description(),
+        );
+    } else if let Some(similar) = most_similar(&name, here.iter().map(|(vname, _, _)| *vname)) {
+        let _ = write!(msg, "\n\nDid you mean `{}`?", similar);
+    }
+
+    if here.is_empty() {
+        let _ = write!(
+            msg,
+            "\n\nThere are no `#[zero(...)]` attributes for {}",
+            position.description(),
+        );
+    } else {
+        let _ = write!(
+            msg,
+            "\n\nThe attributes that can be used on {} are:",
+            position.description(),
+        );
+        for (vname, vform, _) in &here {
+            let _ = write!(msg, "\n- {}", vform.display(vname));
+        }
+    }
+
+    spanned_err! {path,"{}",msg}
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Constructs the error for an unrecognized warning inside `#[zero(allow(...))]`,
+/// suggesting similarly named warnings.
+fn unrecognized_warning(path: &Path, name: &str) -> syn::Error {
+    use std::fmt::Write;
+
+    let names = Warning::ALL.iter().map(|w| w.name());
+
+    let mut msg = format!("Unrecognized warning: `{}`", name);
+
+    if let Some(similar) = most_similar(name, names.clone()) {
+        let _ = write!(msg, "\n\nDid you mean `{}`?", similar);
+    }
+
+    let _ = write!(msg, "\n\nThe warnings that can be allowed are:");
+    for name in names {
+        let _ = write!(msg, "\n- `{}`", name);
+    }
+
+    spanned_err! {path,"{}",msg}
+}
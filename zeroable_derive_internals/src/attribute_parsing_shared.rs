@@ -0,0 +1,85 @@
+//! Helpers for parsing attributes.
+
+use syn::{Meta, NestedMeta};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Iterates over an iterator of syn::NestedMeta,
+/// unwrapping it into a syn::Meta and passing it into the `f` closure.
+pub fn with_nested_meta<I, F>(attr_name: &str, iter: I, mut f: F) -> Result<(), syn::Error>
+where
+    F: FnMut(Meta) -> Result<(), syn::Error>,
+    I: IntoIterator<Item = NestedMeta>,
+{
+    for repr in iter {
+        match repr {
+            NestedMeta::Meta(attr) => {
+                f(attr)?;
+            }
+            NestedMeta::Lit(lit) => {
+                return_spanned_err!(
+                    lit,
+                    "the #[{}(...)] attribute does not allow literals in the attribute list",
+                    attr_name,
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the candidate most similar to `name`,
+/// for "did you mean" suggestions in error messages.
+///
+/// Returns None if no candidate is similar enough.
+///
+/// # Example
+///
+/// ```rust
+/// use zeroable_derive_internals::attribute_parsing_shared::most_similar;
+///
+/// let attrs = ["nonzero", "zeroable", "keep"];
+///
+/// assert_eq!( most_similar("non_zero", attrs.iter().cloned()), Some("nonzero") );
+/// assert_eq!( most_similar("zeroed", attrs.iter().cloned()), None );
+///
+/// ```
+pub fn most_similar<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    // Allows about one typo for every three characters.
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between `left` and `right`,
+/// the amount of single character insertions,deletions,and substitutions
+/// required to turn one string into the other.
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<char>>();
+
+    let mut prev_row = (0..=right.len()).collect::<Vec<usize>>();
+    let mut curr_row = vec![0; right.len() + 1];
+
+    for (i, lc) in left.chars().enumerate() {
+        curr_row[0] = i + 1;
+        for (j, &rc) in right.iter().enumerate() {
+            let substitution = prev_row[j] + (lc != rc) as usize;
+            let deletion = prev_row[j + 1] + 1;
+            let insertion = curr_row[j] + 1;
+            curr_row[j + 1] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    prev_row[right.len()]
+}
@@ -1,3 +1,6 @@
+//! A representation of a type definition (struct,enum,or union),
+//! with its variants and fields.
+
 use syn::{
     self, Attribute, Data, DeriveInput, Field as SynField, Fields as SynFields, Generics, Ident,
     Type, Visibility,
@@ -14,10 +17,14 @@ use std::fmt::{self, Display};
 /// A type definition(enum,struct,union).
 #[derive(Clone)]
 pub struct DataStructure<'a> {
+    /// The visibility of the type.
     pub vis: &'a Visibility,
+    /// The name of the type.
     pub name: &'a Ident,
+    /// The generic parameters and where clause of the type.
     pub generics: &'a Generics,
 
+    /// The attributes on the type.
     pub attrs: &'a [Attribute],
 
     /// Whether this is a struct/union/enum.
@@ -30,6 +37,7 @@ pub struct DataStructure<'a> {
 }
 
 impl<'a> DataStructure<'a> {
+    /// Constructs a `DataStructure` from the input of a derive macro.
     pub fn new(ast: &'a DeriveInput) -> Self {
         let name = &ast.ident;
 
@@ -93,6 +101,7 @@ impl<'a> DataStructure<'a> {
         }
     }
 
+    /// Whether the type is `pub`.
     pub fn is_public(&self) -> bool {
         match self.vis {
             Visibility::Public { .. } => true,
@@ -103,6 +112,7 @@ impl<'a> DataStructure<'a> {
 
 //////////////////////////////////////////////////////////////////////////////
 
+/// Whether a type definition is a struct,enum,or union.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub enum DataVariant {
     Struct,
@@ -110,9 +120,12 @@ pub enum DataVariant {
     Union,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Hash)]
+/// The position of a field in a type definition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FieldIndex {
+    /// The index of the variant,which is always 0 for structs and unions.
     pub variant: usize,
+    /// The position of the field in the variant.
     pub pos: usize,
 }
 
@@ -142,6 +155,7 @@ pub struct Struct<'a> {
     ///
     /// If this is an enum:this is the name of the variant.
     pub name: &'a Ident,
+    /// The fields of this `Struct`.
     pub fields: Vec<MyField<'a>>,
     /// The value of this discriminant.
     ///
@@ -189,11 +203,15 @@ impl<'a> Struct<'a> {
 ///
 #[derive(Clone)]
 pub struct MyField<'a> {
+    /// The position of the field in the type definition.
     pub index: FieldIndex,
+    /// The attributes on the field.
     pub attrs: &'a [Attribute],
+    /// The visibility of the field,which is the visibility of the enum for enum fields.
     pub vis: &'a Visibility,
     /// identifier for the field,which is either an index(in a tuple struct) or a name.
     pub ident: FieldIdent<'a>,
+    /// The type of the field.
     pub ty: &'a Type,
 }
 
@@ -236,7 +254,8 @@ impl<'a> MyField<'a> {
     }
 
     /// Gets the identifier of this field as an `&Ident`.
-    #[allow(dead_code)]
+    ///
+    /// For tuple fields,this is `field_<position>`,eg:`field_0`.
     pub fn ident(&self) -> &Ident {
         match &self.ident {
             FieldIdent::Index(_, ident) => ident,
@@ -244,11 +263,13 @@ impl<'a> MyField<'a> {
         }
     }
 
+    /// The span of the type of the field.
     pub fn ty_span(&self) -> Span {
         use syn::spanned::Spanned;
         self.ty.span()
     }
 
+    /// Whether the field is `pub`.
     pub fn is_public(&self) -> bool {
         match self.vis {
             Visibility::Public { .. } => true,
@@ -259,9 +280,15 @@ impl<'a> MyField<'a> {
 
 //////////////////////////////////////////////////////////////////////////////
 
+/// The identifier of a field.
+///
+/// This is printed,and converted to tokens,as the name of the field,
+/// or as its position for tuple fields.
 #[derive(Clone)]
 pub enum FieldIdent<'a> {
+    /// A tuple field,with its position and a `field_<position>` identifier.
     Index(usize, Ident),
+    /// A named field.
     Named(&'a Ident),
 }
 
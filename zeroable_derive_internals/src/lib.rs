@@ -0,0 +1,71 @@
+/*!
+The parsing model used by the derive macros in
+[`zeroable_derive`](https://docs.rs/zeroable_derive/0.2),
+for use in other derive macros.
+
+# Modules
+
+- [`datastructure`](./datastructure/index.html):
+  A representation of a type definition (struct,enum,or union),
+  with its variants and fields.
+
+- [`repr_attr`](./repr_attr/index.html):
+  Parsing of `#[repr(...)]` attributes.
+
+- [`attribute_parsing_shared`](./attribute_parsing_shared/index.html):
+  Helpers for parsing attributes.
+
+- [`attribute_parsing`](./attribute_parsing/index.html):
+  Parsing of the `#[zero(...)]` attributes of the zeroable derive macros.
+
+- [`zero_variant`](./zero_variant/index.html):
+  Determines which variant of an enum is the one with all zero bytes.
+
+- [`type_param_usage`](./type_param_usage/index.html):
+  Determines which type parameters are used by which fields.
+
+# Example
+
+```rust
+use zeroable_derive_internals::{
+    datastructure::{DataStructure, DataVariant},
+    repr_attr::ReprAttr,
+    type_param_usage::TypeParamUsage,
+    zero_variant::zero_variant_index,
+};
+
+let input: syn::DeriveInput = syn::parse_str("
+    #[repr(u8)]
+    enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+").unwrap();
+
+let ds = DataStructure::new(&input);
+assert_eq!( ds.data_variant, DataVariant::Enum );
+
+let zero_variant = zero_variant_index(&ds, ReprAttr::IntegerRepr).unwrap();
+assert_eq!( ds.variants[zero_variant].name, "Left" );
+
+let usage = TypeParamUsage::new(&ds);
+let left_field = ds.variants[0].fields[0].index;
+assert!( usage.is_used_by(left_field, 0) );
+assert!( !usage.is_used_by(left_field, 1) );
+```
+
+*/
+
+#[macro_use]
+mod macros;
+
+pub mod attribute_parsing;
+pub mod attribute_parsing_shared;
+pub mod datastructure;
+pub mod repr_attr;
+pub mod type_param_usage;
+pub mod utils;
+pub mod zero_variant;
+
+#[cfg(test)]
+mod tests;
@@ -1,3 +1,5 @@
+//! Parsing of `#[repr(...)]` attributes.
+
 use crate::attribute_parsing_shared::with_nested_meta;
 
 use quote::ToTokens;
@@ -6,23 +8,31 @@ use syn::{Meta, NestedMeta};
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The representation of a type,parsed from its `#[repr(...)]` attributes.
+///
+/// `#[repr(align(...))]` is ignored.
 #[derive(Debug, Copy, Clone, PartialEq)]
-#[allow(dead_code)]
-pub(crate) enum ReprAttr {
+pub enum ReprAttr {
+    /// `#[repr(C)]`,`integer_repr` is true if it's combined with an integer repr,
+    /// eg:`#[repr(C,u8)]`.
     C { integer_repr: bool },
+    /// An integer repr,eg:`#[repr(u8)]`.
     IntegerRepr,
+    /// `#[repr(transparent)]`
     Transparent,
+    /// The default representation.
     Rust,
 }
 
 #[derive(Copy, Clone)]
-pub(crate) struct ReprAttrBuilder {
+struct ReprAttrBuilder {
     c: bool,
     integer: bool,
     transparent: bool,
 }
 
 impl ReprAttr {
+    /// Parses the contents of one `#[repr(...)]` attribute.
     pub fn new<I>(iter: I) -> Result<Self, syn::Error>
     where
         I: IntoIterator<Item = NestedMeta>,
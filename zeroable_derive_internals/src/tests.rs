@@ -0,0 +1,89 @@
+use crate::{
+    datastructure::{DataStructure, FieldIndex},
+    repr_attr::ReprAttr,
+    type_param_usage::TypeParamUsage,
+    zero_variant::zero_variant_index,
+};
+
+use syn::DeriveInput;
+
+fn parse(s: &str) -> DeriveInput {
+    syn::parse_str(s).unwrap()
+}
+
+fn repr_of(input: &DeriveInput) -> ReprAttr {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .fold(ReprAttr::Rust, |_, attr| match attr.parse_meta().unwrap() {
+            syn::Meta::List(list) => ReprAttr::new(list.nested).unwrap(),
+            _ => panic!("expected a list"),
+        })
+}
+
+#[test]
+fn zero_variant_test() {
+    let cases = vec![
+        ("#[repr(u8)] enum E{ A, B }", Ok("A")),
+        ("#[repr(C)] enum E{ A=1, B, C=0 }", Ok("C")),
+        ("#[repr(i8)] enum E{ A=-1, B=0, C=1 }", Ok("B")),
+        ("#[repr(C,u8)] enum E{ A(u32), B }", Ok("A")),
+        ("#[repr(transparent)] enum E{ A(u32) }", Ok("A")),
+        ("#[repr(u8)] enum E{ A=1, B }", Err(())),
+        ("enum E{ A, B }", Err(())),
+        ("#[repr(u8)] enum E{}", Err(())),
+    ];
+
+    for (code, expected) in cases {
+        let input = parse(code);
+        let ds = DataStructure::new(&input);
+        let found = zero_variant_index(&ds, repr_of(&input))
+            .map(|i| ds.variants[i].name.to_string())
+            .map_err(drop);
+        assert_eq!(found.as_deref().map_err(|_| ()), expected, "{}", code);
+    }
+}
+
+#[test]
+fn type_param_usage_test() {
+    let input = parse(
+        "
+        struct Hello<'a, T, U, V, const N: usize> {
+            a: &'a T,
+            b: [Option<U>; N],
+            c: <V as Iterator>::Item,
+            d: U::Assoc,
+            e: ::T,
+            f: u32,
+        }
+    ",
+    );
+    let ds = DataStructure::new(&input);
+    let usage = TypeParamUsage::new(&ds);
+
+    let used_by = |pos: usize| {
+        usage
+            .used_by(FieldIndex { variant: 0, pos })
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        usage
+            .type_params()
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>(),
+        vec!["T", "U", "V"]
+    );
+    assert_eq!(used_by(0), vec!["T"]);
+    assert_eq!(used_by(1), vec!["U"]);
+    assert_eq!(used_by(2), vec!["V"]);
+    assert_eq!(used_by(3), vec!["U"]);
+    assert_eq!(used_by(4), Vec::<String>::new());
+    assert_eq!(used_by(5), Vec::<String>::new());
+
+    assert!(usage.is_used_by_any(&ds.variants[0].fields[..2], 1));
+    assert!(!usage.is_used_by_any(&ds.variants[0].fields[..2], 2));
+}
@@ -0,0 +1,106 @@
+//! Determines which type parameters are used by which fields.
+
+use crate::datastructure::{DataStructure, FieldIndex, MyField};
+
+use syn::{
+    visit::{self, Visit},
+    Ident, Path, Type,
+};
+
+/// Which type parameters of a type are mentioned in the type of each field.
+///
+/// Type parameters are detected by name,
+/// so a type parameter is considered used by a field if it appears anywhere in its type,
+/// including inside associated types (eg:`<T as Trait>::Assoc`),and in `T::Assoc`.
+///
+/// Type parameters used only inside macro invocations in type position are not detected.
+#[derive(Debug, Clone)]
+pub struct TypeParamUsage<'a> {
+    type_params: Vec<&'a Ident>,
+    /// Indexed by variant,then by field,then by type parameter.
+    usage: Vec<Vec<Vec<bool>>>,
+}
+
+impl<'a> TypeParamUsage<'a> {
+    /// Computes which type parameters are used by each field in `ds`.
+    pub fn new(ds: &'_ DataStructure<'a>) -> Self {
+        let type_params = ds
+            .generics
+            .type_params()
+            .map(|x| &x.ident)
+            .collect::<Vec<&'a Ident>>();
+
+        let usage = ds
+            .variants
+            .iter()
+            .map(|variant| {
+                variant
+                    .fields
+                    .iter()
+                    .map(|field| type_params_in(&type_params, field.ty))
+                    .collect()
+            })
+            .collect();
+
+        Self { type_params, usage }
+    }
+
+    /// The type parameters of the type,in declaration order.
+    pub fn type_params(&self) -> &[&'a Ident] {
+        &self.type_params
+    }
+
+    /// Whether the field at `index` uses the type parameter at position `param`
+    /// (in the list of type parameters,ignoring lifetime and const parameters).
+    pub fn is_used_by(&self, index: FieldIndex, param: usize) -> bool {
+        self.usage[index.variant][index.pos][param]
+    }
+
+    /// Iterates over the type parameters that the field at `index` uses.
+    pub fn used_by<'s>(&'s self, index: FieldIndex) -> impl Iterator<Item = &'a Ident> + 's {
+        self.type_params
+            .iter()
+            .zip(&self.usage[index.variant][index.pos])
+            .filter(|(_, is_used)| **is_used)
+            .map(|(param, _)| *param)
+    }
+
+    /// Whether any of `fields` uses the type parameter at position `param`.
+    pub fn is_used_by_any<'f, I>(&self, fields: I, param: usize) -> bool
+    where
+        'a: 'f,
+        I: IntoIterator<Item = &'f MyField<'a>>,
+    {
+        fields
+            .into_iter()
+            .any(|field| self.is_used_by(field.index, param))
+    }
+}
+
+/// Returns which of the `type_params` are mentioned in `ty`.
+fn type_params_in(type_params: &[&Ident], ty: &Type) -> Vec<bool> {
+    let mut visitor = TypeParamVisitor {
+        type_params,
+        used: vec![false; type_params.len()],
+    };
+    visitor.visit_type(ty);
+    visitor.used
+}
+
+struct TypeParamVisitor<'a, 'b> {
+    type_params: &'b [&'a Ident],
+    used: Vec<bool>,
+}
+
+impl<'ast> Visit<'ast> for TypeParamVisitor<'_, '_> {
+    fn visit_path(&mut self, path: &'ast Path) {
+        if path.leading_colon.is_none() {
+            if let Some(first) = path.segments.first() {
+                if let Some(pos) = self.type_params.iter().position(|tp| **tp == first.ident) {
+                    self.used[pos] = true;
+                }
+            }
+        }
+        visit::visit_path(self, path);
+    }
+}
@@ -1,3 +1,5 @@
+//! Miscellaneous utilities.
+
 use quote::ToTokens;
 
 use syn::{Expr, ExprLit, Lit, LitInt};
@@ -6,13 +8,16 @@ use std::fmt::Display;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub(crate) fn spanned_err(tokens: &dyn ToTokens, display: &dyn Display) -> syn::Error {
+/// Constructs a `syn::Error` spanning `tokens`,with `display` as the message.
+pub fn spanned_err(tokens: &dyn ToTokens, display: &dyn Display) -> syn::Error {
     syn::Error::new_spanned(tokens, display)
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub(crate) trait ExprExt {
+/// Extension trait for literal expressions.
+pub trait ExprExt {
+    /// Whether this is the `0` integer literal.
     fn is_zero(&self) -> bool;
 }
 
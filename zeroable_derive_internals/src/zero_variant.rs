@@ -0,0 +1,63 @@
+//! Determines which variant of an enum is the one with all zero bytes.
+
+use crate::{
+    datastructure::{DataStructure, DataVariant},
+    repr_attr::ReprAttr,
+    utils::ExprExt,
+};
+
+/// Gets the index of the variant of an enum that is all zero bytes,
+/// returning an error if the enum can't have a zero variant.
+///
+/// The zero variant is:
+///
+/// - For `#[repr(C)]` and `#[repr(<integer_type>)]` enums:
+///   the first variant if it has an implicit discriminant,
+///   otherwise the variant with an explicit `0` discriminant.
+///
+/// - For `#[repr(transparent)]` enums: the only variant.
+///
+/// Enums with the default representation,and enums without variants,
+/// don't have a zero variant.
+///
+/// # Panics
+///
+/// Panics if `ds` is not an enum.
+pub fn zero_variant_index(ds: &'_ DataStructure<'_>, repr: ReprAttr) -> Result<usize, syn::Error> {
+    assert_eq!(ds.data_variant, DataVariant::Enum);
+
+    const REPR_ERR: &str = "Expected a `#[repr(C/<integer_type>/Transparent)]`enum";
+
+    const ENUM_DISCR_ERR: &str = "
+Expected either:
+
+- The first variant to have an implicit discriminant,
+
+- Any variant with an explicit `0` discriminant,
+    ";
+
+    if ds.variants.len() == 0 {
+        return_spanned_err! { ds.name,"Zero variant enums cannot implement Zeroable." }
+    }
+
+    let zero_variant = match repr {
+        ReprAttr::C { .. } | ReprAttr::IntegerRepr { .. } => {
+            if ds.variants[0].discriminant.map_or(true, ExprExt::is_zero) {
+                0
+            } else {
+                let offset = 1;
+                ds.variants[offset..]
+                    .iter()
+                    .position(|var| var.discriminant.map_or(false, ExprExt::is_zero))
+                    .ok_or_else(move || spanned_err! { ds.name,"{}",ENUM_DISCR_ERR })?
+                    + offset
+            }
+        }
+        ReprAttr::Transparent => 0,
+        ReprAttr::Rust => {
+            return_spanned_err! { ds.name,"{}",REPR_ERR }
+        }
+    };
+
+    Ok(zero_variant)
+}
@@ -0,0 +1,427 @@
+//! Listing the places where reading a field of a zeroed value is unsound.
+//!
+//! This goes through every struct,enum,and union that derives `Zeroable`,
+//! parsing its attributes the same way that the derive macro does,
+//! and reports:
+//!
+//! - The union fields that aren't required to be zeroable,
+//!   from the `#[zero(nonzero)]` and `#[zero(nonzero_fields)]` attributes,
+//!   or from not being the first field of a `#[repr(transparent)]` union.
+//!
+//! - The fields of the enum variants other than the one with a `0` discriminant.
+//!
+//! - The type parameters that aren't required to be zeroable,
+//!   from the `#[zero(not_zeroable(...))]` and `#[zero(bound="")]` attributes.
+//!
+//! The report only contains the path of the file that defines each type,
+//! not the line,so that it only changes when the types or their attributes change.
+
+use crate::source_files::{is_zeroable_bound, ParseError, SourceFile};
+
+use zeroable_derive_internals::{
+    attribute_parsing::{parse_attrs_for_zeroed, ZeroConfig},
+    datastructure::{DataStructure, DataVariant},
+    repr_attr::ReprAttr,
+    zero_variant::zero_variant_index,
+};
+
+use syn::{
+    visit::{self, Visit},
+    Attribute, DeriveInput, Ident, ItemEnum, ItemMod, ItemStruct, ItemUnion, Meta, NestedMeta,
+    Type, WherePredicate,
+};
+
+use std::{
+    fmt::{self, Display, Write},
+    path::PathBuf,
+};
+
+/// The result of auditing the types of a crate that derive `Zeroable`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Audit {
+    /// The types that derive `Zeroable`,in the order that they're defined.
+    pub types: Vec<AuditedType>,
+    /// The files that couldn't be parsed.
+    pub parse_errors: Vec<ParseError>,
+}
+
+/// What a type that derives `Zeroable` allows to be non-zeroable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditedType {
+    /// The file that defines the type.
+    pub path: PathBuf,
+    /// The path to the type from the file,eg:`inner::Foo`.
+    pub name: String,
+    /// Either `"struct"`,`"enum"`,or `"union"`.
+    pub kind: &'static str,
+    /// The union fields that aren't required to be zeroable.
+    pub nonzero_union_fields: Vec<String>,
+    /// The fields of the variants that aren't the zero variant,
+    /// as `Variant.field`.
+    pub nonzero_variant_fields: Vec<String>,
+    /// The type parameters that aren't required to be zeroable.
+    pub not_zeroable_params: Vec<String>,
+    /// The error that the derive macro would report for the type,
+    /// in which case the other fields may be incomplete.
+    pub error: Option<String>,
+}
+
+impl AuditedType {
+    /// Whether reading any field of a zeroed value of this type could be unsound.
+    pub fn has_findings(&self) -> bool {
+        !self.nonzero_union_fields.is_empty()
+            || !self.nonzero_variant_fields.is_empty()
+            || !self.not_zeroable_params.is_empty()
+            || self.error.is_some()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Audits the types that derive `Zeroable` in `files`,
+/// where `files` are all the source files of a crate.
+pub fn audit(files: &[SourceFile]) -> Audit {
+    let mut types = Vec::new();
+    let mut parse_errors = Vec::new();
+
+    for source in files {
+        let file = match source.parse() {
+            Ok(file) => file,
+            Err(e) => {
+                parse_errors.push(e);
+                continue;
+            }
+        };
+
+        let mut collector = DeriveCollector::default();
+        collector.visit_file(&file);
+
+        types.extend(
+            collector
+                .defs
+                .iter()
+                .map(|(module, def)| audit_type(source, module, def)),
+        );
+    }
+
+    Audit {
+        types,
+        parse_errors,
+    }
+}
+
+#[derive(Default)]
+struct DeriveCollector {
+    module: Vec<String>,
+    defs: Vec<(Vec<String>, DeriveInput)>,
+}
+
+impl DeriveCollector {
+    fn push_def(&mut self, attrs: &[Attribute], def: impl FnOnce() -> DeriveInput) {
+        if derives_zeroable(attrs) {
+            self.defs.push((self.module.clone(), def()));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DeriveCollector {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        self.module.push(item.ident.to_string());
+        visit::visit_item_mod(self, item);
+        self.module.pop();
+    }
+
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.push_def(&item.attrs, || item.clone().into());
+        visit::visit_item_struct(self, item);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.push_def(&item.attrs, || item.clone().into());
+        visit::visit_item_enum(self, item);
+    }
+
+    fn visit_item_union(&mut self, item: &'ast ItemUnion) {
+        self.push_def(&item.attrs, || item.clone().into());
+        visit::visit_item_union(self, item);
+    }
+}
+
+/// Whether there's a `#[derive(...)]` attribute that includes `Zeroable`.
+fn derives_zeroable(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(meta) => meta
+                .path()
+                .segments
+                .last()
+                .map_or(false, |seg| seg.ident == "Zeroable"),
+            NestedMeta::Lit(_) => false,
+        })
+}
+
+fn audit_type(source: &SourceFile, module: &[String], def: &DeriveInput) -> AuditedType {
+    let ds = &DataStructure::new(def);
+
+    let mut audited = AuditedType {
+        path: source.path.clone(),
+        name: module
+            .iter()
+            .map(|x| &**x)
+            .chain(std::iter::once(&*def.ident.to_string()))
+            .collect::<Vec<_>>()
+            .join("::"),
+        kind: match ds.data_variant {
+            DataVariant::Struct => "struct",
+            DataVariant::Enum => "enum",
+            DataVariant::Union => "union",
+        },
+        nonzero_union_fields: Vec::new(),
+        nonzero_variant_fields: Vec::new(),
+        not_zeroable_params: Vec::new(),
+        error: None,
+    };
+
+    let config = match parse_attrs_for_zeroed(ds) {
+        Ok(config) => config,
+        Err(e) => {
+            audited.error = Some(e.to_string());
+            return audited;
+        }
+    };
+
+    audited.not_zeroable_params = not_zeroable_params(ds, &config);
+
+    match ds.data_variant {
+        DataVariant::Struct => {}
+        DataVariant::Enum => match zero_variant_index(ds, config.repr_attr()) {
+            Ok(zero_variant) => {
+                audited.nonzero_variant_fields = ds
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != zero_variant)
+                    .flat_map(|(_, variant)| {
+                        variant
+                            .fields
+                            .iter()
+                            .map(move |field| format!("{}.{}", variant.name, field.ident))
+                    })
+                    .collect();
+            }
+            Err(e) => audited.error = Some(e.to_string()),
+        },
+        DataVariant::Union => {
+            let fields = &ds.variants[0].fields;
+            audited.nonzero_union_fields = if config.repr_attr() == ReprAttr::Transparent {
+                fields.iter().skip(1).map(|f| f.ident.to_string()).collect()
+            } else {
+                fields
+                    .iter()
+                    .filter(|f| !config.is_zeroable_field(f.index))
+                    .map(|f| f.ident.to_string())
+                    .collect()
+            };
+        }
+    }
+
+    audited
+}
+
+/// The type parameters that don't get a `Zeroable` bound,
+/// neither from the derive macro nor from the definition of the type.
+fn not_zeroable_params(ds: &DataStructure<'_>, config: &ZeroConfig<'_>) -> Vec<String> {
+    let where_preds = ds
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|x| &x.predicates)
+        .chain(config.extra_predicates())
+        .collect::<Vec<_>>();
+
+    ds.generics
+        .type_params()
+        .enumerate()
+        .filter(|(pos, _)| !config.has_auto_bound(*pos))
+        .filter(|(_, param)| {
+            let in_params = param.bounds.iter().any(is_zeroable_bound);
+            let in_where = where_preds
+                .iter()
+                .any(|pred| bounds_param_by_zeroable(pred, &param.ident));
+            !in_params && !in_where
+        })
+        .map(|(_, param)| param.ident.to_string())
+        .collect()
+}
+
+fn bounds_param_by_zeroable(pred: &WherePredicate, param: &Ident) -> bool {
+    match pred {
+        WherePredicate::Type(pred) => {
+            let is_param = match &pred.bounded_ty {
+                Type::Path(ty) => ty.qself.is_none() && ty.path.is_ident(param),
+                _ => false,
+            };
+            is_param && pred.bounds.iter().any(is_zeroable_bound)
+        }
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The format of an audit report.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+    /// A JSON object,with a `types` array and a `parse_errors` array.
+    Json,
+    /// A Markdown document,with a section for each type.
+    Markdown,
+}
+
+impl Format {
+    /// Parses the format from its name,either `json` or `markdown`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Format::Json),
+            "markdown" | "md" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+}
+
+impl Audit {
+    /// Writes the report in the `format` format.
+    pub fn report(&self, format: Format) -> String {
+        let mut out = String::new();
+        match format {
+            Format::Json => self.write_json(&mut out),
+            Format::Markdown => self.write_markdown(&mut out),
+        }
+        .expect("writing to a String can't fail");
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "{{")?;
+        writeln!(out, "  \"types\": [")?;
+        for (i, ty) in self.types.iter().enumerate() {
+            writeln!(out, "    {{")?;
+            writeln!(out, "      \"path\": {},", JsonStr(&ty.path.display()))?;
+            writeln!(out, "      \"name\": {},", JsonStr(&ty.name))?;
+            writeln!(out, "      \"kind\": {},", JsonStr(&ty.kind))?;
+            for (key, list) in [
+                ("nonzero_union_fields", &ty.nonzero_union_fields),
+                ("nonzero_variant_fields", &ty.nonzero_variant_fields),
+                ("not_zeroable_params", &ty.not_zeroable_params),
+            ] {
+                write!(out, "      \"{}\": [", key)?;
+                for (j, elem) in list.iter().enumerate() {
+                    let sep = if j == 0 { "" } else { ", " };
+                    write!(out, "{}{}", sep, JsonStr(elem))?;
+                }
+                writeln!(out, "],")?;
+            }
+            match &ty.error {
+                Some(error) => writeln!(out, "      \"error\": {}", JsonStr(error))?,
+                None => writeln!(out, "      \"error\": null")?,
+            }
+            let sep = if i + 1 == self.types.len() { "" } else { "," };
+            writeln!(out, "    }}{}", sep)?;
+        }
+        writeln!(out, "  ],")?;
+        if self.parse_errors.is_empty() {
+            writeln!(out, "  \"parse_errors\": []")?;
+        } else {
+            writeln!(out, "  \"parse_errors\": [")?;
+            for (i, error) in self.parse_errors.iter().enumerate() {
+                let sep = if i + 1 == self.parse_errors.len() {
+                    ""
+                } else {
+                    ","
+                };
+                writeln!(out, "    {}{}", JsonStr(error), sep)?;
+            }
+            writeln!(out, "  ]")?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn write_markdown(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "# Zeroable audit")?;
+        for ty in &self.types {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "## {} `{}` (`{}`)",
+                ty.kind,
+                ty.name,
+                ty.path.display()
+            )?;
+            writeln!(out)?;
+            if let Some(error) = &ty.error {
+                writeln!(out, "- The derive macro errors: {}", error)?;
+            }
+            for (desc, list) in [
+                (
+                    "Union fields that may be non-zeroable",
+                    &ty.nonzero_union_fields,
+                ),
+                (
+                    "Fields of non-zero enum variants",
+                    &ty.nonzero_variant_fields,
+                ),
+                (
+                    "Type parameters that may be non-zeroable",
+                    &ty.not_zeroable_params,
+                ),
+            ] {
+                if !list.is_empty() {
+                    writeln!(out, "- {}: `{}`", desc, list.join("`, `"))?;
+                }
+            }
+            if !ty.has_findings() {
+                writeln!(
+                    out,
+                    "- No fields or type parameters that may be non-zeroable."
+                )?;
+            }
+        }
+        if !self.parse_errors.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "## Parse errors")?;
+            writeln!(out)?;
+            for error in &self.parse_errors {
+                writeln!(out, "- {}", error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Displays a value as a JSON string literal.
+struct JsonStr<'a, T: ?Sized>(&'a T);
+
+impl<T: ?Sized + Display> Display for JsonStr<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.0.to_string().chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,62 @@
+//! Lists the places where reading a field of a zeroed value is unsound,
+//! for the types that derive `Zeroable`.
+//!
+//! Usage: `zeroable-audit [--format json|markdown] <crate source directories or files>...`
+
+use zeroable_tools::{
+    audit::{audit, Format},
+    source_files::SourceFile,
+};
+
+use std::{env, process};
+
+const USAGE: &str = "\
+Usage: zeroable-audit [--format json|markdown] <crate source directories or files>...
+
+Lists the union fields,enum variant fields,and type parameters that may be non-zeroable,
+for every type that derives `Zeroable`.
+The report is printed in the Markdown format by default.";
+
+fn main() {
+    let mut format = Format::Markdown;
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &*arg {
+            "--format" => {
+                let name = args.next().unwrap_or_default();
+                format = Format::from_name(&name).unwrap_or_else(|| {
+                    eprintln!("Unrecognized format: `{}`\n\n{}", name, USAGE);
+                    process::exit(2);
+                });
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if arg.starts_with('-') => {
+                eprintln!("Unrecognized option: `{}`\n\n{}", arg, USAGE);
+                process::exit(2);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let files = SourceFile::read_all(&paths).unwrap_or_else(|e| {
+        eprintln!("Couldn't read the source files: {}", e);
+        process::exit(1);
+    });
+
+    let audit = audit(&files);
+
+    print!("{}", audit.report(format));
+
+    if !audit.parse_errors.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,73 @@
+//! Rewrites manual `unsafe impl Zeroable` blocks into `#[derive(zeroable::Zeroable)]`.
+//!
+//! Usage: `zeroable-migrate [--write] <crate source directories or files>...`
+
+use zeroable_tools::{migrate::migrate, source_files::SourceFile};
+
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage: zeroable-migrate [--write] <crate source directories or files>...
+
+Rewrites manual `unsafe impl Zeroable` blocks into `#[derive(zeroable::Zeroable)]`.
+Without `--write`,it only prints the changes it would make.";
+
+fn main() {
+    let mut write = false;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match &*arg {
+            "--write" => write = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if arg.starts_with('-') => {
+                eprintln!("Unrecognized option: `{}`\n\n{}", arg, USAGE);
+                process::exit(2);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let files = SourceFile::read_all(&paths).unwrap_or_else(|e| {
+        eprintln!("Couldn't read the source files: {}", e);
+        process::exit(1);
+    });
+
+    let migration = migrate(&files);
+
+    for error in &migration.parse_errors {
+        eprintln!("{}", error);
+    }
+    for conversion in &migration.conversions {
+        println!("{}", conversion);
+    }
+    for unconvertible in &migration.unconvertible {
+        println!("{}", unconvertible);
+    }
+
+    if write {
+        for file in &migration.edited_files {
+            if let Err(e) = fs::write(&file.path, &file.text) {
+                eprintln!("Couldn't write `{}`: {}", file.path.display(), e);
+                process::exit(1);
+            }
+        }
+    }
+
+    println!(
+        "\n{} {} impl(s) into derives,{} impl(s) couldn't be converted.",
+        if write { "Converted" } else { "Would convert" },
+        migration.conversions.len(),
+        migration.unconvertible.len(),
+    );
+    if !write && !migration.edited_files.is_empty() {
+        println!("Run with `--write` to apply the changes.");
+    }
+}
@@ -0,0 +1,55 @@
+/*!
+Command-line tools for migrating to,and auditing uses of,the
+[`zeroable`](https://docs.rs/zeroable/0.2) derive macros.
+
+# Tools
+
+### `zeroable-migrate`
+
+Rewrites manual `unsafe impl Zeroable for Type {}` blocks into
+`#[derive(zeroable::Zeroable)]` attributes on the type,
+reporting the impls it can't convert and why.
+
+```text
+zeroable-migrate [--write] <crate source directories or files>...
+```
+
+Without `--write` it only prints what it would do.
+
+The rules for which impls are converted are documented in the
+[`migrate`](./migrate/index.html) module.
+
+### `zeroable-audit`
+
+Lists the places where reading a field of a zeroed value is unsound,
+for every type that derives `Zeroable`:
+the union fields that may be non-zeroable,
+the fields of the enum variants other than the zero variant,
+and the type parameters that may be non-zeroable.
+
+```text
+zeroable-audit [--format json|markdown] <crate source directories or files>...
+```
+
+The report doesn't include line numbers,
+so that it can be diffed between commits in CI.
+
+# Modules
+
+- [`source_files`](./source_files/index.html):
+  Reading the source files of a crate,and mapping spans back to their text.
+
+- [`migrate`](./migrate/index.html):
+  Rewriting manual `unsafe impl Zeroable` blocks into `#[derive(Zeroable)]`.
+
+- [`audit`](./audit/index.html):
+  Listing the places where reading a field of a zeroed value is unsound.
+
+*/
+
+pub mod audit;
+pub mod migrate;
+pub mod source_files;
+
+#[cfg(test)]
+mod tests;
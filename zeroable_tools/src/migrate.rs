@@ -0,0 +1,721 @@
+//! Rewriting manual `unsafe impl Zeroable` blocks into `#[derive(Zeroable)]`.
+//!
+//! An impl is converted when:
+//!
+//! - It's an `unsafe impl` of a trait named `Zeroable`,without items or attributes.
+//!
+//! - It's for a struct,enum,or union defined in the same crate,
+//!   with the type's own generic parameters as its generic arguments.
+//!
+//! - The type satisfies the restrictions of the derive macro:
+//!   enums need a variant with a `0` discriminant,
+//!   and unions need at least one field whose type is known to be zeroable
+//!   (primitives,raw pointers,type parameters bounded by `Zeroable`,and arrays/tuples of those).
+//!
+//! The generic parameters without a `Zeroable` bound in the impl
+//! become `#[zero(not_zeroable(...))]` parameters,
+//! and the other bounds of the impl become `#[zero(bound(...))]` predicates.
+//!
+//! Unions where only some fields are known to be zeroable get the
+//! `#[zero(nonzero_fields)]` attribute,with `#[zero(zeroable)]` on those fields.
+//!
+//! The `// comments` right before the impl are moved above the derive attribute,
+//! since they usually explain why the impl is sound (eg:`// SAFETY:` comments).
+
+use crate::source_files::{is_zeroable_bound, Edit, Location, ParseError, SourceFile};
+
+use zeroable_derive_internals::{
+    datastructure::{DataStructure, DataVariant, MyField},
+    repr_attr::ReprAttr,
+    zero_variant::zero_variant_index,
+};
+
+use proc_macro2::Span;
+
+use syn::{
+    spanned::Spanned,
+    visit::{self, Visit},
+    Attribute, Data, DeriveInput, GenericArgument, GenericParam, Generics, Ident, ItemEnum,
+    ItemImpl, ItemStruct, ItemUnion, Meta, PathArguments, Type, TypeParamBound, Visibility,
+    WherePredicate,
+};
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+/// The attribute that replaces manual impls.
+pub const DERIVE_ATTR: &str = "#[derive(zeroable::Zeroable)]";
+
+/// The result of migrating the manual `Zeroable` impls of a crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    /// The impls that were converted into derives.
+    pub conversions: Vec<Conversion>,
+    /// The impls that couldn't be converted,with the reason why.
+    pub unconvertible: Vec<Unconvertible>,
+    /// The files that couldn't be parsed.
+    pub parse_errors: Vec<ParseError>,
+    /// The new contents of the files that changed.
+    pub edited_files: Vec<SourceFile>,
+}
+
+/// A manual impl that was converted into a derive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// Where the impl was.
+    pub location: Location,
+    /// The name of the type.
+    pub type_name: String,
+    /// The attributes added to the type.
+    pub attributes: Vec<String>,
+    /// The names of the fields that got the `#[zero(zeroable)]` attribute.
+    pub zeroable_fields: Vec<String>,
+    /// The `//` comment lines before the impl,which were moved above the derive attribute.
+    pub moved_comments: Vec<String>,
+}
+
+/// A manual impl that couldn't be converted into a derive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unconvertible {
+    /// Where the impl is.
+    pub location: Location,
+    /// The type that the impl is for.
+    pub self_ty: String,
+    /// Why the impl couldn't be converted.
+    pub reason: String,
+}
+
+impl Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: converted the impl for `{}` into {}",
+            self.location,
+            self.type_name,
+            self.attributes.join(" "),
+        )?;
+        if !self.zeroable_fields.is_empty() {
+            write!(
+                f,
+                ",with `#[zero(zeroable)]` on the `{}` field(s)",
+                self.zeroable_fields.join("`,`"),
+            )?;
+        }
+        if !self.moved_comments.is_empty() {
+            write!(
+                f,
+                ",moving the comments before the impl above the derive:\n{}",
+                self.moved_comments.join("\n"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Unconvertible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: can't convert the impl for `{}`: {}",
+            self.location, self.self_ty, self.reason,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts the manual `Zeroable` impls in `files` into derives,
+/// where `files` are all the source files of a crate.
+///
+/// This doesn't write any files,the new contents are in `Migration::edited_files`.
+pub fn migrate(files: &[SourceFile]) -> Migration {
+    let mut parse_errors = Vec::new();
+    let mut defs = HashMap::<String, Vec<(usize, DeriveInput)>>::new();
+    let mut impls = Vec::<(usize, ItemImpl)>::new();
+
+    for (file_index, source) in files.iter().enumerate() {
+        let file = match source.parse() {
+            Ok(file) => file,
+            Err(e) => {
+                parse_errors.push(e);
+                continue;
+            }
+        };
+
+        let mut collector = ItemCollector::default();
+        collector.visit_file(&file);
+
+        for def in collector.defs {
+            defs.entry(def.ident.to_string())
+                .or_default()
+                .push((file_index, def));
+        }
+        impls.extend(collector.impls.into_iter().map(|imp| (file_index, imp)));
+    }
+
+    let mut conversions = Vec::new();
+    let mut unconvertible = Vec::new();
+    let mut edits = vec![Vec::<Edit>::new(); files.len()];
+
+    for (file_index, imp) in &impls {
+        let source = &files[*file_index];
+        match convert_impl(files, &defs, source, imp) {
+            Ok((conversion, def_file, def_edits)) => {
+                edits[*file_index].push(remove_item(source, imp));
+                edits[def_file].extend(def_edits);
+                conversions.push(conversion);
+            }
+            Err(reason) => unconvertible.push(Unconvertible {
+                location: source.location(imp.span()),
+                self_ty: source.snippet(imp.self_ty.span()).to_string(),
+                reason,
+            }),
+        }
+    }
+
+    let edited_files = files
+        .iter()
+        .zip(&edits)
+        .filter(|(_, edits)| !edits.is_empty())
+        .map(|(source, edits)| {
+            SourceFile::new(source.path.clone(), Edit::apply_all(&source.text, edits))
+        })
+        .collect();
+
+    Migration {
+        conversions,
+        unconvertible,
+        parse_errors,
+        edited_files,
+    }
+}
+
+#[derive(Default)]
+struct ItemCollector {
+    defs: Vec<DeriveInput>,
+    impls: Vec<ItemImpl>,
+}
+
+impl<'ast> Visit<'ast> for ItemCollector {
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.defs.push(item.clone().into());
+        visit::visit_item_struct(self, item);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.defs.push(item.clone().into());
+        visit::visit_item_enum(self, item);
+    }
+
+    fn visit_item_union(&mut self, item: &'ast ItemUnion) {
+        self.defs.push(item.clone().into());
+        visit::visit_item_union(self, item);
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        let is_zeroable_impl = match (&item.unsafety, &item.trait_) {
+            (Some(_), Some((None, path, _))) => path
+                .segments
+                .last()
+                .map_or(false, |seg| seg.ident == "Zeroable"),
+            _ => false,
+        };
+        if is_zeroable_impl {
+            self.impls.push(item.clone());
+        }
+        visit::visit_item_impl(self, item);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the conversion,the index of the file that defines the type,
+/// and the edits to that file.
+fn convert_impl(
+    files: &[SourceFile],
+    defs: &HashMap<String, Vec<(usize, DeriveInput)>>,
+    source: &SourceFile,
+    imp: &ItemImpl,
+) -> Result<(Conversion, usize, Vec<Edit>), String> {
+    if !imp.attrs.is_empty() {
+        return Err("the impl has attributes,which the derive can't reproduce".to_string());
+    }
+    if !imp.items.is_empty() {
+        return Err("the impl overrides items of the `Zeroable` trait".to_string());
+    }
+
+    let self_path = match &*imp.self_ty {
+        Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+        _ => return Err("the impl isn't for a struct,enum,or union".to_string()),
+    };
+    let last_segment = self_path.segments.last().expect("paths aren't empty");
+    let type_name = last_segment.ident.to_string();
+
+    let (def_file, def) = match defs.get(&type_name).map(|x| &**x) {
+        None | Some([]) => return Err(format!("`{}` isn't defined in this crate", type_name)),
+        Some([def]) => def,
+        Some(_) => {
+            return Err(format!(
+                "there are multiple types named `{}` in this crate,\
+                 so it's ambiguous which one the impl is for",
+                type_name,
+            ))
+        }
+    };
+    let def_source = &files[*def_file];
+
+    check_generic_args(&def.generics, &last_segment.arguments)?;
+
+    let ds = DataStructure::new(def);
+    let repr = parse_repr(&def.attrs).map_err(|e| e.to_string())?;
+
+    let bounds = impl_bounds(source, def_source, imp, &def.generics);
+
+    let mut zero_attrs = Vec::new();
+
+    let not_zeroable = def
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .filter(|ident| !bounds.zeroable_params.contains(*ident))
+        .map(|ident| ident.to_string())
+        .collect::<Vec<_>>();
+    if !not_zeroable.is_empty() {
+        zero_attrs.push(format!("not_zeroable({})", not_zeroable.join(", ")));
+    }
+    if !bounds.predicates.is_empty() {
+        zero_attrs.push(format!("bound({})", bounds.predicates.join(", ")));
+    }
+
+    let mut zeroable_fields = Vec::new();
+
+    match ds.data_variant {
+        DataVariant::Struct => {}
+        DataVariant::Enum => {
+            zero_variant_index(&ds, repr)
+                .map_err(|e| format!("the enum can't derive `Zeroable`: {}", e))?;
+        }
+        DataVariant::Union => {
+            let fields = &ds.variants[0].fields;
+            if fields.is_empty() {
+                return Err("unions without fields can't derive `Zeroable`".to_string());
+            }
+
+            if repr != ReprAttr::Transparent {
+                let known_zeroable = fields
+                    .iter()
+                    .filter(|field| is_known_zeroable(field.ty, &bounds.zeroable_params))
+                    .collect::<Vec<&MyField<'_>>>();
+
+                if known_zeroable.is_empty() {
+                    return Err(
+                        "none of the union fields has a type that's known to be zeroable,\
+                         the derive requires at least one field to be zeroable"
+                            .to_string(),
+                    );
+                } else if known_zeroable.len() < fields.len() {
+                    zero_attrs.push("nonzero_fields".to_string());
+                    zeroable_fields = known_zeroable;
+                }
+            }
+        }
+    }
+
+    let mut attributes = vec![DERIVE_ATTR.to_string()];
+    if !zero_attrs.is_empty() {
+        attributes.push(format!("#[zero({})]", zero_attrs.join(", ")));
+    }
+
+    let (_, comments) = preceding_comments(source, imp);
+
+    let def_start = item_start(&def.vis, keyword_span(def));
+    let mut def_edits = Vec::new();
+    if !comments.is_empty() {
+        def_edits.push(insert_comments(def_source, def_start, &comments));
+    }
+    def_edits.push(insert_attrs(def_source, def_start, &attributes));
+    def_edits.extend(zeroable_fields.iter().map(|field| {
+        insert_attrs(
+            def_source,
+            item_start(field.vis, field.ident().span()),
+            &["#[zero(zeroable)]".to_string()],
+        )
+    }));
+
+    let conversion = Conversion {
+        location: source.location(imp.span()),
+        type_name,
+        attributes,
+        zeroable_fields: zeroable_fields
+            .iter()
+            .map(|f| f.ident.to_string())
+            .collect(),
+        moved_comments: comments,
+    };
+
+    Ok((conversion, *def_file, def_edits))
+}
+
+/// Checks that the generic arguments of the impl's self type
+/// are the generic parameters of the type definition.
+fn check_generic_args(generics: &Generics, args: &PathArguments) -> Result<(), String> {
+    let args = match args {
+        PathArguments::None => Vec::new(),
+        PathArguments::AngleBracketed(args) => args.args.iter().collect(),
+        PathArguments::Parenthesized(_) => {
+            return Err("the impl isn't for a struct,enum,or union".to_string())
+        }
+    };
+
+    let matches = args.len() == generics.params.len()
+        && args
+            .iter()
+            .zip(&generics.params)
+            .all(|(arg, param)| match (arg, param) {
+                (GenericArgument::Lifetime(arg), GenericParam::Lifetime(param)) => {
+                    *arg == param.lifetime
+                }
+                (GenericArgument::Type(Type::Path(arg)), GenericParam::Type(param)) => {
+                    arg.qself.is_none() && arg.path.is_ident(&param.ident)
+                }
+                (GenericArgument::Type(Type::Path(arg)), GenericParam::Const(param)) => {
+                    arg.qself.is_none() && arg.path.is_ident(&param.ident)
+                }
+                _ => false,
+            });
+
+    if matches {
+        Ok(())
+    } else {
+        Err("the impl is only for some instantiations of the type,\
+             its generic arguments must be the generic parameters of the type"
+            .to_string())
+    }
+}
+
+fn parse_repr(attrs: &[Attribute]) -> Result<ReprAttr, syn::Error> {
+    let mut repr = ReprAttr::Rust;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            repr = ReprAttr::new(list.nested)?;
+        }
+    }
+    Ok(repr)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct ImplBounds {
+    /// The type parameters with a `Zeroable` bound.
+    zeroable_params: HashSet<Ident>,
+    /// The predicates that aren't `Zeroable` bounds on type parameters,
+    /// nor in the type definition,as source code.
+    predicates: Vec<String>,
+}
+
+fn impl_bounds(
+    source: &SourceFile,
+    def_source: &SourceFile,
+    imp: &ItemImpl,
+    def_generics: &Generics,
+) -> ImplBounds {
+    let mut zeroable_params = HashSet::new();
+    let type_params = imp
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<HashSet<&Ident>>();
+
+    let def_predicates = definition_predicates(def_source, def_generics);
+    let mut predicates = Vec::new();
+
+    for param in &imp.generics.params {
+        match param {
+            GenericParam::Type(param) => predicates.extend(split_param_bounds(
+                source,
+                &def_predicates,
+                &mut zeroable_params,
+                &param.ident,
+                &param.bounds,
+            )),
+            GenericParam::Lifetime(param) if !param.bounds.is_empty() => {
+                predicates.push(source.snippet(param.span()).to_string());
+            }
+            _ => {}
+        }
+    }
+
+    let where_preds = imp.generics.where_clause.iter().flat_map(|x| &x.predicates);
+    for pred in where_preds {
+        let param = match pred {
+            WherePredicate::Type(pred) if pred.lifetimes.is_none() => match &pred.bounded_ty {
+                Type::Path(ty) if ty.qself.is_none() => ty
+                    .path
+                    .get_ident()
+                    .filter(|ident| type_params.contains(ident))
+                    .map(|ident| (ident, pred)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match param {
+            Some((ident, pred)) => predicates.extend(split_param_bounds(
+                source,
+                &def_predicates,
+                &mut zeroable_params,
+                ident,
+                &pred.bounds,
+            )),
+            None => predicates.push(source.snippet(pred.span()).to_string()),
+        }
+    }
+
+    predicates.retain(|pred| !def_predicates.contains(&without_whitespace(pred)));
+
+    ImplBounds {
+        zeroable_params,
+        predicates,
+    }
+}
+
+/// Adds `ident` to `zeroable_params` if `bounds` has a `Zeroable` bound,
+/// returning the bounds that aren't in `def_predicates` as a predicate.
+fn split_param_bounds<'a, I>(
+    source: &SourceFile,
+    def_predicates: &HashSet<String>,
+    zeroable_params: &mut HashSet<Ident>,
+    ident: &Ident,
+    bounds: I,
+) -> Option<String>
+where
+    I: IntoIterator<Item = &'a TypeParamBound>,
+{
+    let mut other_bounds = Vec::new();
+    for bound in bounds {
+        let bound_str = source.snippet(bound.span());
+        if is_zeroable_bound(bound) {
+            zeroable_params.insert(ident.clone());
+        } else if !def_predicates.contains(&without_whitespace(&format!("{}:{}", ident, bound_str)))
+        {
+            other_bounds.push(bound_str);
+        }
+    }
+
+    if other_bounds.is_empty() {
+        None
+    } else {
+        Some(format!("{}: {}", ident, other_bounds.join(" + ")))
+    }
+}
+
+/// The predicates of the type definition,without whitespace,
+/// which are copied into the derived impl.
+///
+/// This includes every bound of type parameters as a separate predicate.
+fn definition_predicates(source: &SourceFile, generics: &Generics) -> HashSet<String> {
+    let mut predicates = Vec::new();
+
+    for param in &generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                for bound in &param.bounds {
+                    predicates.push(format!("{}:{}", param.ident, source.snippet(bound.span())));
+                }
+            }
+            GenericParam::Lifetime(param) if !param.bounds.is_empty() => {
+                predicates.push(source.snippet(param.span()).to_string());
+            }
+            _ => {}
+        }
+    }
+
+    for pred in generics.where_clause.iter().flat_map(|x| &x.predicates) {
+        if let WherePredicate::Type(pred) = pred {
+            let bounded_ty = source.snippet(pred.bounded_ty.span());
+            for bound in &pred.bounds {
+                predicates.push(format!("{}:{}", bounded_ty, source.snippet(bound.span())));
+            }
+        }
+        predicates.push(source.snippet(pred.span()).to_string());
+    }
+
+    predicates
+        .iter()
+        .map(|pred| without_whitespace(pred))
+        .collect()
+}
+
+fn without_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Whether `ty` is known to implement `Zeroable` without looking at other type definitions.
+fn is_known_zeroable(ty: &Type, zeroable_params: &HashSet<Ident>) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+        "f32", "f64", "bool", "char",
+    ];
+
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            let last = match ty.path.segments.last() {
+                Some(x) => x,
+                None => return false,
+            };
+            let type_args = match &last.arguments {
+                PathArguments::None => Vec::new(),
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                PathArguments::Parenthesized(_) => return false,
+            };
+            let name = last.ident.to_string();
+
+            match (&*name, &*type_args) {
+                (_, []) if ty.path.segments.len() == 1 && PRIMITIVES.contains(&&*name) => true,
+                (_, []) if zeroable_params.contains(&last.ident) => true,
+                ("PhantomData", _) | ("PhantomPinned", []) | ("MaybeUninit", [_]) => true,
+                ("ManuallyDrop", [arg]) | ("Wrapping", [arg]) | ("Cell", [arg]) => {
+                    is_known_zeroable(arg, zeroable_params)
+                }
+                ("Option", [Type::Reference(_)]) => true,
+                _ => false,
+            }
+        }
+        Type::Ptr(_) => true,
+        Type::Array(array) => is_known_zeroable(&array.elem, zeroable_params),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .all(|elem| is_known_zeroable(elem, zeroable_params)),
+        Type::Paren(paren) => is_known_zeroable(&paren.elem, zeroable_params),
+        Type::Group(group) => is_known_zeroable(&group.elem, zeroable_params),
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn keyword_span(def: &DeriveInput) -> Span {
+    match &def.data {
+        Data::Struct(x) => x.struct_token.span,
+        Data::Enum(x) => x.enum_token.span,
+        Data::Union(x) => x.union_token.span,
+    }
+}
+
+/// Where an item or field starts,after its attributes.
+fn item_start(vis: &Visibility, after_vis: Span) -> Span {
+    match vis {
+        Visibility::Inherited => after_vis,
+        _ => vis.span(),
+    }
+}
+
+/// Inserts `attrs` before `at`,each on its own line if `at` starts a line.
+fn insert_attrs(source: &SourceFile, at: Span, attrs: &[String]) -> Edit {
+    let offset = source.offset(at.start());
+    let line_start = source.line_start(offset);
+    let before = &source.text[line_start..offset];
+
+    if before.trim().is_empty() {
+        Edit {
+            start: line_start,
+            end: line_start,
+            replacement: attrs
+                .iter()
+                .map(|attr| format!("{}{}\n", before, attr))
+                .collect(),
+        }
+    } else {
+        Edit {
+            start: offset,
+            end: offset,
+            replacement: attrs.iter().map(|attr| format!("{} ", attr)).collect(),
+        }
+    }
+}
+
+/// Inserts the `comments` lines in the line before the line that `at` is in,
+/// with the same indentation as that line.
+fn insert_comments(source: &SourceFile, at: Span, comments: &[String]) -> Edit {
+    let offset = source.offset(at.start());
+    let line_start = source.line_start(offset);
+    let line = &source.text[line_start..offset];
+    let indentation = &line[..line.len() - line.trim_start().len()];
+
+    Edit {
+        start: line_start,
+        end: line_start,
+        replacement: comments
+            .iter()
+            .map(|comment| format!("{}{}\n", indentation, comment))
+            .collect(),
+    }
+}
+
+/// Returns where the `//` comments in the lines right before `imp` start,
+/// along with those comments (without indentation).
+///
+/// If `imp` doesn't start its line,this returns the start of `imp` and no comments.
+fn preceding_comments(source: &SourceFile, imp: &ItemImpl) -> (usize, Vec<String>) {
+    let text = &*source.text;
+    let mut start = source.offset(imp.span().start());
+    let mut comments = Vec::new();
+
+    let line_start = source.line_start(start);
+    if text[line_start..start].trim().is_empty() {
+        start = line_start;
+
+        while start != 0 {
+            let prev_line_start = source.line_start(start - 1);
+            let prev_line = text[prev_line_start..start].trim();
+            let is_comment = prev_line.starts_with("//")
+                && !prev_line.starts_with("///")
+                && !prev_line.starts_with("//!");
+            if !is_comment {
+                break;
+            }
+            comments.push(prev_line.to_string());
+            start = prev_line_start;
+        }
+    }
+
+    comments.reverse();
+    (start, comments)
+}
+
+/// Removes `imp`,along with the `//` comments in the lines right before it
+/// (which `convert_impl` moves to the type definition).
+fn remove_item(source: &SourceFile, imp: &ItemImpl) -> Edit {
+    let text = &*source.text;
+    let (start, _) = preceding_comments(source, imp);
+    let mut end = source.offset(imp.span().end());
+
+    let line_end = source.line_end(end);
+    if text[end..line_end].trim().is_empty() {
+        end = line_end;
+
+        // Avoids leaving two blank lines where the impl was.
+        let prev_is_blank =
+            start == 0 || text[source.line_start(start - 1)..start].trim().is_empty();
+        let next_line_end = source.line_end(end);
+        if prev_is_blank && end != text.len() && text[end..next_line_end].trim().is_empty() {
+            end = next_line_end;
+        }
+    }
+
+    Edit {
+        start,
+        end,
+        replacement: String::new(),
+    }
+}
@@ -0,0 +1,232 @@
+//! Reading the source files of a crate,and mapping spans back to their text.
+
+use proc_macro2::{LineColumn, Span};
+
+use syn::{TraitBoundModifier, TypeParamBound};
+
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The path and contents of a Rust source file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The contents of the file.
+    pub text: String,
+    /// The byte offset where each line starts.
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    /// Constructs a `SourceFile` from its path and contents.
+    pub fn new<P>(path: P, text: String) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+
+        Self {
+            path: path.into(),
+            text,
+            line_starts,
+        }
+    }
+
+    /// Reads every `.rs` file in `paths`,recursing into directories,
+    /// skipping `target` directories and hidden directories.
+    ///
+    /// The files are sorted by path.
+    pub fn read_all<I, P>(paths: I) -> io::Result<Vec<SourceFile>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut file_paths = Vec::new();
+        for path in paths {
+            collect_rs_files(path.as_ref(), &mut file_paths)?;
+        }
+        file_paths.sort();
+        file_paths.dedup();
+
+        file_paths
+            .into_iter()
+            .map(|path| {
+                let text = fs::read_to_string(&path)?;
+                Ok(SourceFile::new(path, text))
+            })
+            .collect()
+    }
+
+    /// Parses the contents of the file.
+    ///
+    /// The spans of the parsed file can be passed to the methods of this type.
+    pub fn parse(&self) -> Result<syn::File, ParseError> {
+        syn::parse_file(&self.text).map_err(|e| ParseError {
+            location: self.location(e.span()),
+            message: e.to_string(),
+        })
+    }
+
+    /// The byte offset of a line and column,as returned by `Span::start` and `Span::end`.
+    pub fn offset(&self, lc: LineColumn) -> usize {
+        let line_start = match self.line_starts.get(lc.line.saturating_sub(1)) {
+            Some(&x) => x,
+            None => return self.text.len(),
+        };
+
+        self.text[line_start..]
+            .char_indices()
+            .nth(lc.column)
+            .map_or(self.text.len(), |(i, _)| line_start + i)
+    }
+
+    /// The byte offset where the line containing `offset` starts.
+    pub fn line_start(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => self.line_starts[line],
+            Err(line) => self.line_starts[line - 1],
+        }
+    }
+
+    /// The byte offset where the line containing `offset` ends,including the newline.
+    pub fn line_end(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |i| offset + i + 1)
+    }
+
+    /// The source code that `span` covers.
+    pub fn snippet(&self, span: Span) -> &str {
+        &self.text[self.offset(span.start())..self.offset(span.end())]
+    }
+
+    /// The location of `span` in this file.
+    pub fn location(&self, span: Span) -> Location {
+        Location {
+            path: self.path.clone(),
+            line: span.start().line,
+        }
+    }
+}
+
+fn collect_rs_files(path: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            let entry_path = entry?.path();
+            let is_skipped = entry_path.is_dir()
+                && entry_path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| name == "target" || name.starts_with('.'));
+
+            if !is_skipped {
+                collect_rs_files(&entry_path, out)?;
+            }
+        }
+    } else if path.extension().map_or(false, |ext| ext == "rs") {
+        out.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A line in a source file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The line number,starting from 1.
+    pub line: usize,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.path.display(), self.line)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A file that couldn't be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Where the error is.
+    pub location: Location,
+    /// The error message.
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: couldn't parse the file: {}",
+            self.location, self.message
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A replacement of a byte range in a source file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit {
+    /// The start of the replaced range.
+    pub start: usize,
+    /// The end of the replaced range,which is `start` for insertions.
+    pub end: usize,
+    /// The text that replaces the range.
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Applies `edits` to `text`.
+    ///
+    /// Insertions at the same offset are applied in the order they're passed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the edited ranges overlap.
+    pub fn apply_all(text: &str, edits: &[Edit]) -> String {
+        let mut edits = edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| (edit.start, edit.end));
+
+        let mut out = String::with_capacity(text.len());
+        let mut pos = 0;
+        for edit in edits {
+            assert!(pos <= edit.start, "overlapping edits: {:?}", edit);
+            out.push_str(&text[pos..edit.start]);
+            out.push_str(&edit.replacement);
+            pos = edit.end;
+        }
+        out.push_str(&text[pos..]);
+        out
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether `bound` is a plain `Zeroable` bound (eg:`Zeroable`,`zeroable::Zeroable`).
+///
+/// Bounds with a modifier (eg:`?Zeroable`),with higher-ranked lifetimes (eg:`for<'a> Zeroable`),
+/// and with generic arguments (eg:`Zeroable<T>`) aren't considered `Zeroable` bounds,
+/// since they don't (or might not) require the type to implement the `Zeroable` trait.
+pub(crate) fn is_zeroable_bound(bound: &TypeParamBound) -> bool {
+    match bound {
+        TypeParamBound::Trait(bound) => {
+            bound.lifetimes.is_none()
+                && matches!(bound.modifier, TraitBoundModifier::None)
+                && bound.path.segments.last().map_or(false, |seg| {
+                    seg.ident == "Zeroable" && seg.arguments.is_empty()
+                })
+        }
+        TypeParamBound::Lifetime(_) => false,
+    }
+}
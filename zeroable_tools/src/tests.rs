@@ -0,0 +1,455 @@
+use crate::{
+    audit::{audit, AuditedType, Format},
+    migrate::{migrate, Migration},
+    source_files::SourceFile,
+};
+
+fn migrate_str(text: &str) -> Migration {
+    migrate(&[SourceFile::new("lib.rs", text.to_string())])
+}
+
+fn migrated_text(text: &str) -> String {
+    let migration = migrate_str(text);
+    assert_eq!(migration.unconvertible, vec![], "{}", text);
+    migration.edited_files[0].text.clone()
+}
+
+fn reasons(text: &str) -> Vec<String> {
+    let migration = migrate_str(text);
+    assert_eq!(migration.conversions, vec![], "{}", text);
+    migration
+        .unconvertible
+        .into_iter()
+        .map(|x| x.reason)
+        .collect()
+}
+
+#[test]
+fn migrate_struct_test() {
+    assert_eq!(
+        migrated_text(
+            "\
+use bytemuck::Zeroable;
+
+/// A point.
+#[derive(Copy, Clone)]
+pub struct Point {
+    x: u32,
+    y: u32,
+}
+
+// SAFETY: all the fields are integers.
+unsafe impl Zeroable for Point {}
+
+fn foo() {}
+"
+        ),
+        "\
+use bytemuck::Zeroable;
+
+/// A point.
+#[derive(Copy, Clone)]
+// SAFETY: all the fields are integers.
+#[derive(zeroable::Zeroable)]
+pub struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn foo() {}
+",
+    );
+
+    assert_eq!(
+        migrated_text(
+            "\
+mod inner {
+    struct Wrapper<'a, T: Copy, U>(&'a T, U);
+}
+
+// SAFETY: references to `T` aren't zeroed,
+// only the `U` field is.
+unsafe impl<'a, T, U> bytemuck::Zeroable for inner::Wrapper<'a, T, U>
+where
+    T: Copy + Send,
+    U: Zeroable + 'static,
+    Vec<T>: Clone,
+{}
+"
+        ),
+        "\
+mod inner {
+    // SAFETY: references to `T` aren't zeroed,
+    // only the `U` field is.
+    #[derive(zeroable::Zeroable)]
+    #[zero(not_zeroable(T), bound(T: Send, U: 'static, Vec<T>: Clone))]
+    struct Wrapper<'a, T: Copy, U>(&'a T, U);
+}
+
+",
+    );
+}
+
+#[test]
+fn migrate_moved_comments_test() {
+    let migration = migrate_str(
+        "\
+struct Foo(u8); struct Bar(u8);
+
+// SAFETY: `Bar` only contains an integer.
+unsafe impl Zeroable for Bar {}
+",
+    );
+    assert_eq!(
+        migration.edited_files[0].text,
+        "\
+// SAFETY: `Bar` only contains an integer.
+struct Foo(u8); #[derive(zeroable::Zeroable)] struct Bar(u8);
+
+",
+    );
+
+    let conversion = &migration.conversions[0];
+    assert_eq!(
+        conversion.moved_comments,
+        vec!["// SAFETY: `Bar` only contains an integer.".to_string()],
+    );
+    assert!(conversion
+        .to_string()
+        .ends_with("above the derive:\n// SAFETY: `Bar` only contains an integer."));
+}
+
+#[test]
+fn migrate_enum_test() {
+    assert_eq!(
+        migrated_text(
+            "\
+#[repr(u8)]
+enum Either<L, R> { Left(L), Right(R) }
+unsafe impl<L: Zeroable, R> Zeroable for Either<L, R> {}
+"
+        ),
+        "\
+#[repr(u8)]
+#[derive(zeroable::Zeroable)]
+#[zero(not_zeroable(R))]
+enum Either<L, R> { Left(L), Right(R) }
+",
+    );
+
+    let reasons = reasons(
+        "\
+#[repr(u8)]
+enum NoZero { A = 1, B = 2 }
+unsafe impl Zeroable for NoZero {}
+",
+    );
+    assert_eq!(reasons.len(), 1);
+    assert!(
+        reasons[0].starts_with("the enum can't derive `Zeroable`"),
+        "{}",
+        reasons[0]
+    );
+}
+
+#[test]
+fn migrate_union_test() {
+    assert_eq!(
+        migrated_text(
+            "\
+union Bits { int: u32, float: f32, bytes: [u8; 4] }
+unsafe impl Zeroable for Bits {}
+"
+        ),
+        "\
+#[derive(zeroable::Zeroable)]
+union Bits { int: u32, float: f32, bytes: [u8; 4] }
+",
+    );
+
+    assert_eq!(
+        migrated_text(
+            "\
+pub union Either<T: Copy> {
+    pub value: T,
+    pub reference: &'static u8,
+    pub raw: *const u8,
+}
+unsafe impl<T: Copy> Zeroable for Either<T> {}
+"
+        ),
+        "\
+#[derive(zeroable::Zeroable)]
+#[zero(not_zeroable(T), nonzero_fields)]
+pub union Either<T: Copy> {
+    pub value: T,
+    pub reference: &'static u8,
+    #[zero(zeroable)]
+    pub raw: *const u8,
+}
+",
+    );
+
+    assert_eq!(
+        reasons(
+            "\
+union Refs { a: &'static u8, b: Custom }
+unsafe impl Zeroable for Refs {}
+"
+        ),
+        vec![
+            "none of the union fields has a type that's known to be zeroable,\
+             the derive requires at least one field to be zeroable"
+                .to_string()
+        ],
+    );
+}
+
+#[test]
+fn unconvertible_test() {
+    assert_eq!(
+        reasons("unsafe impl Zeroable for Foreign {}"),
+        vec!["`Foreign` isn't defined in this crate".to_string()],
+    );
+    assert_eq!(
+        reasons(
+            "
+            struct Foo<T>(T);
+            unsafe impl Zeroable for Foo<u8> {}
+            "
+        ),
+        vec!["the impl is only for some instantiations of the type,\
+             its generic arguments must be the generic parameters of the type"
+            .to_string()],
+    );
+    assert_eq!(
+        reasons(
+            "
+            struct Foo(u8);
+            unsafe impl Zeroable for Foo {
+                fn zeroed() -> Self { Foo(0) }
+            }
+            "
+        ),
+        vec!["the impl overrides items of the `Zeroable` trait".to_string()],
+    );
+    assert_eq!(
+        reasons(
+            "
+            struct Foo(u8);
+            #[cfg(feature = \"zeroable\")]
+            unsafe impl Zeroable for Foo {}
+            "
+        ),
+        vec!["the impl has attributes,which the derive can't reproduce".to_string()],
+    );
+    assert_eq!(
+        reasons(
+            "
+            mod a { pub struct Foo(u8); }
+            mod b { pub struct Foo(u16); }
+            unsafe impl Zeroable for a::Foo {}
+            "
+        ),
+        vec!["there are multiple types named `Foo` in this crate,\
+             so it's ambiguous which one the impl is for"
+            .to_string()],
+    );
+}
+
+#[test]
+fn migrate_multiple_files_test() {
+    let files = vec![
+        SourceFile::new(
+            "lib.rs",
+            "mod types;\nunsafe impl Zeroable for types::Foo {}\n".into(),
+        ),
+        SourceFile::new("types.rs", "pub struct Foo {\n    x: u8,\n}\n".into()),
+        SourceFile::new("broken.rs", "fn foo( {}".into()),
+    ];
+    let migration = migrate(&files);
+
+    assert_eq!(migration.conversions.len(), 1);
+    assert_eq!(migration.conversions[0].location.to_string(), "lib.rs:2");
+    assert_eq!(migration.parse_errors.len(), 1);
+    assert_eq!(
+        migration.parse_errors[0].location.to_string(),
+        "broken.rs:1"
+    );
+    assert_eq!(
+        migration.edited_files,
+        vec![
+            SourceFile::new("lib.rs", "mod types;\n".into()),
+            SourceFile::new(
+                "types.rs",
+                "#[derive(zeroable::Zeroable)]\npub struct Foo {\n    x: u8,\n}\n".into()
+            ),
+        ],
+    );
+}
+
+fn audit_str(text: &str) -> Vec<AuditedType> {
+    let audit = audit(&[SourceFile::new("lib.rs", text.to_string())]);
+    assert_eq!(audit.parse_errors, vec![], "{}", text);
+    audit.types
+}
+
+fn strings(list: &[&str]) -> Vec<String> {
+    list.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn audit_union_test() {
+    let types = audit_str(
+        "
+        #[derive(Zeroable)]
+        union Bits { int: u32, #[zero(nonzero)] reference: &'static u8 }
+
+        #[derive(Copy, Clone, zeroable::Zeroable)]
+        #[zero(nonzero_fields)]
+        union Opt<T: Copy> { #[zero(zeroable)] none: (), some: T, other: &'static T }
+
+        #[derive(Zeroable)]
+        #[repr(transparent)]
+        union Transparent { int: u32, unit: () }
+
+        #[derive(Clone)]
+        union NotDerived { reference: &'static u8 }
+        ",
+    );
+    let unions = types
+        .iter()
+        .map(|ty| (&*ty.name, ty.kind, ty.nonzero_union_fields.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unions,
+        vec![
+            ("Bits", "union", strings(&["reference"])),
+            ("Opt", "union", strings(&["some", "other"])),
+            ("Transparent", "union", strings(&["unit"])),
+        ]
+    );
+    assert!(types.iter().all(|ty| ty.error.is_none()));
+}
+
+#[test]
+fn audit_enum_and_params_test() {
+    let types = audit_str(
+        "
+        mod inner {
+            #[derive(Zeroable)]
+            #[repr(u8)]
+            #[zero(not_zeroable(R))]
+            enum Either<L, R> { Left(L), Right(R, u8), Neither { x: u8 } }
+        }
+
+        #[derive(Zeroable)]
+        #[zero(bound=\"\")]
+        #[zero(bound=\"A: Zeroable\")]
+        struct NoAutoBounds<A, B, C: zeroable::Zeroable>(A, B, C);
+
+        #[derive(Zeroable)]
+        struct Point { x: u32, y: u32 }
+
+        #[derive(Zeroable)]
+        #[zero(bound=\"\")]
+        struct NotZeroableBounds<A: ?Zeroable, B: Zeroable<u8>, C>(A, B, C)
+        where
+            C: for<'a> Zeroable;
+        ",
+    );
+
+    assert_eq!(types[0].name, "inner::Either");
+    assert_eq!(
+        types[0].nonzero_variant_fields,
+        strings(&["Right.0", "Right.1", "Neither.x"]),
+    );
+    assert_eq!(types[0].not_zeroable_params, strings(&["R"]));
+
+    assert_eq!(types[1].name, "NoAutoBounds");
+    assert_eq!(types[1].not_zeroable_params, strings(&["B"]));
+
+    assert_eq!(types[2].name, "Point");
+    assert!(!types[2].has_findings());
+
+    assert_eq!(types[3].name, "NotZeroableBounds");
+    assert_eq!(types[3].not_zeroable_params, strings(&["A", "B", "C"]));
+}
+
+#[test]
+fn audit_errors_test() {
+    let files = vec![
+        SourceFile::new(
+            "lib.rs",
+            "#[derive(Zeroable)] #[zero(nonzero_fieldz)] union Foo { x: u8 }\n\
+             #[derive(Zeroable)] #[repr(u8)] enum NoZero { A = 1 }\n"
+                .into(),
+        ),
+        SourceFile::new("broken.rs", "fn foo( {}".into()),
+    ];
+    let audit = audit(&files);
+
+    assert_eq!(audit.types.len(), 2);
+    assert!(audit.types.iter().all(|ty| ty.error.is_some()));
+    assert_eq!(audit.parse_errors.len(), 1);
+}
+
+#[test]
+fn audit_report_test() {
+    let files = vec![SourceFile::new(
+        "src/lib.rs",
+        "
+        #[derive(Zeroable)]
+        #[repr(u8)]
+        enum Opt<T> { None, Some(T) }
+
+        #[derive(Zeroable)]
+        struct Unit;
+        "
+        .into(),
+    )];
+    let audit = audit(&files);
+
+    assert_eq!(
+        audit.report(Format::Json),
+        r#"{
+  "types": [
+    {
+      "path": "src/lib.rs",
+      "name": "Opt",
+      "kind": "enum",
+      "nonzero_union_fields": [],
+      "nonzero_variant_fields": ["Some.0"],
+      "not_zeroable_params": [],
+      "error": null
+    },
+    {
+      "path": "src/lib.rs",
+      "name": "Unit",
+      "kind": "struct",
+      "nonzero_union_fields": [],
+      "nonzero_variant_fields": [],
+      "not_zeroable_params": [],
+      "error": null
+    }
+  ],
+  "parse_errors": []
+}
+"#,
+    );
+
+    assert_eq!(
+        audit.report(Format::Markdown),
+        "\
+# Zeroable audit
+
+## enum `Opt` (`src/lib.rs`)
+
+- Fields of non-zero enum variants: `Some.0`
+
+## struct `Unit` (`src/lib.rs`)
+
+- No fields or type parameters that may be non-zeroable.
+",
+    );
+}